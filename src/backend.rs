@@ -0,0 +1,156 @@
+use crate::buffer::{Buffer, Cell};
+use crate::width::{glyph_width, is_ambiguous};
+use crossterm::{
+    cursor, queue,
+    style::{
+        self, Attribute, Attributes, Color, SetAttribute, SetBackgroundColor, SetForegroundColor,
+    },
+    terminal,
+};
+use std::io::{self, Write};
+
+// ── Output backends ───────────────────────────────────────────────────
+
+/// A surface the renderer can draw cells onto.
+pub trait Backend {
+    /// Draw one cell at `(x, y)`. Continuation cells are never passed in.
+    fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()>;
+
+    /// Make everything drawn since the last flush visible.
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Current size as `(cols, rows)`.
+    fn size(&self) -> io::Result<(u16, u16)>;
+}
+
+/// Writes cells to a terminal through crossterm escape sequences.
+///
+/// Consecutive cells on a row are written as one run without cursor moves,
+/// and color/attribute escapes are only sent when they differ from the last
+/// ones written.
+pub struct CrosstermBackend<W: Write> {
+    out: W,
+    cursor: Option<(u16, u16)>,
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attrs: Attributes,
+}
+
+impl<W: Write> CrosstermBackend<W> {
+    pub fn new(out: W) -> Self {
+        CrosstermBackend {
+            out,
+            cursor: None,
+            fg: None,
+            bg: None,
+            attrs: Attributes::default(),
+        }
+    }
+
+    /// The underlying writer, for escapes outside the cell grid (screen
+    /// setup, clearing, cursor visibility).
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.out
+    }
+}
+
+impl<W: Write> Backend for CrosstermBackend<W> {
+    fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()> {
+        if self.cursor != Some((x, y)) {
+            queue!(self.out, cursor::MoveTo(x, y))?;
+        }
+        if cell.attrs != self.attrs {
+            // Resetting attributes also resets colors
+            queue!(self.out, SetAttribute(Attribute::Reset))?;
+            for attr in Attribute::iterator().filter(|&a| cell.attrs.has(a)) {
+                queue!(self.out, SetAttribute(attr))?;
+            }
+            self.attrs = cell.attrs;
+            self.fg = None;
+            self.bg = None;
+        }
+        if self.fg != Some(cell.fg) {
+            queue!(self.out, SetForegroundColor(cell.fg))?;
+            self.fg = Some(cell.fg);
+        }
+        // Nothing to send while the terminal's own background is wanted and
+        // no other was set since the last reset
+        if self.bg != Some(cell.bg) && !(self.bg.is_none() && cell.bg == Color::Reset) {
+            queue!(self.out, SetBackgroundColor(cell.bg))?;
+            self.bg = Some(cell.bg);
+        }
+        queue!(self.out, style::Print(cell.ch))?;
+        // Where the terminal leaves the cursor after an ambiguous glyph is
+        // anyone's guess, so the next cell gets an explicit move
+        self.cursor = if is_ambiguous(cell.ch) {
+            None
+        } else {
+            Some((x + glyph_width(cell.ch, 1), y))
+        };
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.attrs != Attributes::default() {
+            queue!(self.out, SetAttribute(Attribute::Reset))?;
+        } else if self.bg.is_some_and(|bg| bg != Color::Reset) {
+            // Leave the terminal with its own background between frames
+            queue!(self.out, SetBackgroundColor(Color::Reset))?;
+        }
+        // Others may write to the terminal between frames, so forget its state
+        self.cursor = None;
+        self.fg = None;
+        self.bg = None;
+        self.attrs = Attributes::default();
+        self.out.flush()
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        terminal::size()
+    }
+}
+
+/// Headless backend that draws into an in-memory [`Buffer`], for tests,
+/// benchmarks and exporters.
+pub struct MemoryBackend {
+    buffer: Buffer,
+}
+
+impl MemoryBackend {
+    pub fn new(cols: u16, rows: u16) -> Self {
+        MemoryBackend {
+            buffer: Buffer::new(cols, rows),
+        }
+    }
+
+    /// Lay out ambiguous-width glyphs over `width` (1 or 2) cells, matching
+    /// the renderer's setting.
+    pub fn with_ambiguous_width(mut self, width: u8) -> Self {
+        self.buffer = self.buffer.with_ambiguous_width(width);
+        self
+    }
+
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Resize and blank the grid, like a terminal that was resized and cleared.
+    pub fn resize(&mut self, cols: u16, rows: u16) {
+        self.buffer.resize(cols, rows);
+    }
+}
+
+impl Backend for MemoryBackend {
+    fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()> {
+        self.buffer.set(x, y, *cell);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok(self.buffer.size())
+    }
+}
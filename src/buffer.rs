@@ -0,0 +1,178 @@
+use crate::backend::Backend;
+use crate::width::glyph_width;
+use crossterm::style::{Attributes, Color};
+use std::io;
+
+// ── Cell framebuffer ──────────────────────────────────────────────────
+
+/// Marks the right half of a double-width glyph drawn in the cell to its left.
+const CONTINUATION: char = '\0';
+
+/// One terminal cell: a glyph plus its styling. A `bg` of [`Color::Reset`]
+/// leaves the terminal's own background showing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cell {
+    pub ch: char,
+    pub fg: Color,
+    pub bg: Color,
+    pub attrs: Attributes,
+}
+
+impl Cell {
+    pub fn new(ch: char, fg: Color) -> Self {
+        Cell {
+            ch,
+            fg,
+            bg: Color::Reset,
+            attrs: Attributes::default(),
+        }
+    }
+
+    /// A blank cell painted in `bg`.
+    pub fn blank(bg: Color) -> Self {
+        Cell {
+            bg,
+            ..Cell::default()
+        }
+    }
+
+    /// Whether this cell is covered by a wide glyph to its left.
+    pub fn is_continuation(&self) -> bool {
+        self.ch == CONTINUATION
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell::new(' ', Color::Reset)
+    }
+}
+
+/// A `cols × rows` grid of cells, stored row by row.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Buffer {
+    cols: u16,
+    rows: u16,
+    cells: Vec<Cell>,
+    ambiguous_width: u8, // cells taken by East Asian Ambiguous glyphs
+}
+
+impl Buffer {
+    pub fn new(cols: u16, rows: u16) -> Self {
+        Buffer {
+            cols,
+            rows,
+            cells: vec![Cell::default(); cols as usize * rows as usize],
+            ambiguous_width: 1,
+        }
+    }
+
+    /// Lay out ambiguous-width glyphs over `width` (1 or 2) cells.
+    pub fn with_ambiguous_width(mut self, width: u8) -> Self {
+        self.ambiguous_width = width;
+        self
+    }
+
+    pub fn size(&self) -> (u16, u16) {
+        (self.cols, self.rows)
+    }
+
+    pub fn get(&self, x: u16, y: u16) -> Option<&Cell> {
+        if x < self.cols && y < self.rows {
+            self.cells.get(self.index(x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Write a cell, claiming the next cell as well when the glyph is double-width.
+    /// Glyphs that would straddle the right edge are dropped.
+    pub fn set(&mut self, x: u16, y: u16, cell: Cell) {
+        if x >= self.cols || y >= self.rows {
+            return;
+        }
+        let width = glyph_width(cell.ch, self.ambiguous_width);
+        if x + width > self.cols {
+            return;
+        }
+
+        // Overwriting either half of a wide glyph blanks its other half
+        let idx = self.index(x, y);
+        if self.cells[idx].is_continuation() && x > 0 {
+            self.cells[idx - 1] = Cell::blank(self.cells[idx - 1].bg);
+        }
+        let end = x + width;
+        let after = idx + width as usize;
+        if end < self.cols && self.cells[after].is_continuation() {
+            self.cells[after] = Cell::blank(self.cells[after].bg);
+        }
+
+        self.cells[idx] = cell;
+        for i in 1..width as usize {
+            self.cells[idx + i] = Cell {
+                ch: CONTINUATION,
+                ..cell
+            };
+        }
+    }
+
+    /// Write a string starting at `(x, y)`, advancing by each glyph's display
+    /// width, over whatever background the cells already have.
+    pub fn set_str(&mut self, x: u16, y: u16, s: &str, fg: Color, attrs: Attributes) {
+        let mut x = x;
+        for ch in s.chars() {
+            if x >= self.cols {
+                break;
+            }
+            let bg = self.get(x, y).map_or(Color::Reset, |cell| cell.bg);
+            self.set(x, y, Cell { ch, fg, bg, attrs });
+            x += glyph_width(ch, self.ambiguous_width);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.fill(Cell::default());
+    }
+
+    /// Blank row `y` in `bg`.
+    pub fn fill_row(&mut self, y: u16, bg: Color) {
+        if y < self.rows {
+            let start = self.index(0, y);
+            self.cells[start..start + self.cols as usize].fill(Cell::blank(bg));
+        }
+    }
+
+    pub fn resize(&mut self, cols: u16, rows: u16) {
+        *self = Buffer::new(cols, rows).with_ambiguous_width(self.ambiguous_width);
+    }
+
+    /// Rows of the grid as plain text, with wide glyphs occupying their own width.
+    pub fn lines(&self) -> Vec<String> {
+        self.cells
+            .chunks(self.cols.max(1) as usize)
+            .map(|row| {
+                row.iter()
+                    .filter(|cell| !cell.is_continuation())
+                    .map(|cell| cell.ch)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Draw every cell of `self` that differs from `previous` onto `backend`.
+    pub fn diff(&self, previous: &Buffer, backend: &mut impl Backend) -> io::Result<()> {
+        for y in 0..self.rows {
+            for x in 0..self.cols {
+                let cell = &self.cells[self.index(x, y)];
+                if !cell.is_continuation() && previous.get(x, y) != Some(cell) {
+                    backend.draw_cell(x, y, cell)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn index(&self, x: u16, y: u16) -> usize {
+        y as usize * self.cols as usize + x as usize
+    }
+}
@@ -0,0 +1,282 @@
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use runefall::{
+    parse_hex, profile_path, Background, ColorMode, Config, Direction, Palette, RuneSet,
+};
+use std::io;
+use std::path::PathBuf;
+
+// ── Command-line definition ───────────────────────────────────────────
+
+/// One definition drives parsing, `--help`, shell completions and the man
+/// page, so they can't drift apart.
+#[derive(Parser)]
+#[command(
+    name = "runefall",
+    version,
+    about = "Ultra-light runic terminal screensaver",
+    after_help = "Settings are read from $XDG_CONFIG_HOME/runefall/config.toml (or \
+                  $RUNEFALL_CONFIG), then RUNEFALL_PALETTE, RUNEFALL_FPS, RUNEFALL_DENSITY, \
+                  RUNEFALL_RUNES and RUNEFALL_DIRECTION; options given here override both.\n\n\
+                  Press 'q' or Ctrl+C to exit."
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    #[command(flatten)]
+    pub options: Options,
+}
+
+#[derive(Args)]
+pub struct Options {
+    /// Color palette: arcane, emerald, frost, ember, rainbow, blink, one
+    /// defined in the config file, or auto to follow the time of day
+    /// [default: arcane]
+    #[arg(short, long, value_name = "NAME")]
+    palette: Option<String>,
+    /// Derive the palette from a terminal theme: Alacritty TOML or YAML,
+    /// kitty .conf, Xresources or iTerm2 .itermcolors
+    #[arg(long, value_name = "FILE", value_hint = clap::ValueHint::FilePath, conflicts_with = "palette")]
+    palette_from: Option<PathBuf>,
+    /// Tint the auto palette around solstices and equinoxes
+    #[arg(long)]
+    seasons: bool,
+    /// Fill behind the rain: a color, or top and bottom colors of a vertical
+    /// gradient such as #0a1030,#000000 [default: the terminal's]
+    #[arg(long, value_name = "HEX[,HEX]", value_parser = parse_background)]
+    background: Option<Background>,
+    /// Target frames per second, 5-100 [default: 20]
+    #[arg(short, long, value_name = "N", value_parser = clap::value_parser!(u64).range(5..=100))]
+    fps: Option<u64>,
+    /// Column density, 0.05-1.0 [default: 0.4]
+    #[arg(short, long, value_name = "N", value_parser = parse_density)]
+    density: Option<f32>,
+    /// Random seed for a reproducible run
+    #[arg(long, value_name = "N")]
+    seed: Option<u64>,
+    /// truecolor, 256, 16 or mono [default: detected]
+    #[arg(long, value_name = "MODE", value_parser = parse_color_mode)]
+    color_mode: Option<ColorMode>,
+    /// Cells for ambiguous-width symbols, 1 or 2 [default: 1]
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u8).range(1..=2))]
+    ambiguous_width: Option<u8>,
+    /// Built-in set (elder, younger, anglo, ogham, mystic, medieval, turkic,
+    /// italic, gothic, hungarian, tifinagh), rune set file or weighted mix
+    /// such as elder:3,mystic:1
+    #[arg(long, value_name = "SPEC", value_hint = clap::ValueHint::FilePath)]
+    runes: Option<String>,
+    /// Use the given characters as a custom rune set
+    #[arg(long, value_name = "GLYPHS", conflicts_with = "runes")]
+    runes_string: Option<String>,
+    /// Direction the rain falls in [default: down]
+    #[arg(long, value_name = "DIR", value_parser = PossibleValuesParser::new(["down", "up", "left", "right"])
+        .map(|name| Direction::from_name(&name).unwrap()))]
+    direction: Option<Direction>,
+    /// Frames over which palette and rune set switches blend in, 0-1000;
+    /// 0 switches at once [default: 20]
+    #[arg(long, value_name = "FRAMES", value_parser = clap::value_parser!(u64).range(0..=1000))]
+    transition: Option<u64>,
+    /// Start with the status bar hidden ('i' shows it)
+    #[arg(long)]
+    no_status: bool,
+    /// Reveal a phrase in runes in the middle of the screen
+    #[arg(long, value_name = "TEXT")]
+    message: Option<String>,
+    /// Show a "rune of the moment" card now and then
+    #[arg(long)]
+    rune_card: bool,
+    /// Start with a saved profile; 'p' saves back to it
+    #[arg(long, value_name = "NAME")]
+    profile: Option<String>,
+    /// Latin/ASCII stand-ins for fonts without runes
+    #[arg(long)]
+    ascii: bool,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Spell Latin text in runes, reading lines from stdin when no TEXT is given
+    Translit {
+        /// Rune set: elder, younger, anglo, medieval, ogham, turkic, italic,
+        /// gothic, hungarian or tifinagh
+        #[arg(short, long, value_name = "NAME", default_value = "elder", value_parser = parse_rune_set)]
+        set: RuneSet,
+        text: Vec<String>,
+    },
+    /// Print a completion script for bash, zsh or fish
+    Completions { shell: Shell },
+    /// Print the man page in roff format
+    Man,
+}
+
+fn parse_density(s: &str) -> Result<f32, String> {
+    let density: f32 = s.parse().map_err(|_| format!("`{s}` is not a number"))?;
+    if !(0.05..=1.0).contains(&density) {
+        return Err(format!("{density} is not in 0.05-1.0"));
+    }
+    Ok(density)
+}
+
+fn parse_background(s: &str) -> Result<Background, String> {
+    s.split(',')
+        .map(|hex| parse_hex(hex.trim()))
+        .collect::<Option<Vec<_>>>()
+        .and_then(|colors| Background::from_colors(&colors))
+        .ok_or_else(|| "expected one or two #rrggbb colors".to_string())
+}
+
+fn parse_color_mode(s: &str) -> Result<ColorMode, String> {
+    ColorMode::from_name(s).ok_or_else(|| "expected truecolor, 256, 16 or mono".to_string())
+}
+
+fn parse_rune_set(s: &str) -> Result<RuneSet, String> {
+    RuneSet::from_name(s).ok_or_else(|| "unknown rune set".to_string())
+}
+
+impl Options {
+    /// Override `config` with the options that were given. Palettes and rune
+    /// sets are looked up here, as they may come from the config file.
+    pub fn apply(self, mut config: Config) -> io::Result<Config> {
+        let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidInput, msg);
+
+        // A profile is a layer of its own; the other options still win
+        if let Some(name) = self.profile {
+            // A profile that doesn't exist yet is created on first save
+            let path = profile_path(&name)?;
+            if path.exists() {
+                config.load_file(&path)?;
+            }
+            config.profile = Some(name);
+        }
+        if let Some(name) = self.palette {
+            if !config.select_palette(&name) {
+                return Err(invalid(format!("--palette: unknown palette `{name}`")));
+            }
+        }
+        if let Some(path) = self.palette_from {
+            let palette = Palette::from_theme_file(&path)?;
+            config.custom_palettes.push(palette.clone());
+            config.palette = palette;
+            config.auto_palette = false;
+        }
+        config.seasons |= self.seasons;
+        if let Some(spec) = self.runes {
+            let set = config.find_rune_set(&spec)?;
+            if let RuneSet::Custom(_) = set {
+                config.custom_rune_sets.push(set.clone());
+            }
+            config.rune_set = set;
+        }
+        if let Some(glyphs) = self.runes_string {
+            let set = RuneSet::custom("Custom", &glyphs)
+                .ok_or_else(|| invalid("--runes-string has no glyphs".to_string()))?;
+            config.custom_rune_sets.push(set.clone());
+            config.rune_set = set;
+        }
+        config.background = self.background.or(config.background);
+        config.fps = self.fps.unwrap_or(config.fps);
+        config.density = self.density.unwrap_or(config.density);
+        config.direction = self.direction.unwrap_or(config.direction);
+        config.transition = self.transition.unwrap_or(config.transition);
+        config.show_status &= !self.no_status;
+        config.seed = self.seed.or(config.seed);
+        config.color_mode = self.color_mode.unwrap_or(config.color_mode);
+        config.ambiguous_width = self.ambiguous_width.unwrap_or(config.ambiguous_width);
+        config.message = self.message.or(config.message);
+        config.rune_card |= self.rune_card;
+        config.ascii |= self.ascii;
+        Ok(config)
+    }
+}
+
+/// Write the completion script for `shell` to stdout.
+pub fn print_completions(shell: Shell) {
+    clap_complete::generate(shell, &mut Cli::command(), "runefall", &mut io::stdout());
+}
+
+/// Write the man page to stdout.
+pub fn print_man_page() -> io::Result<()> {
+    clap_mangen::Man::new(Cli::command()).render(&mut io::stdout())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Config, String> {
+        let cli =
+            Cli::try_parse_from(["runefall"].iter().chain(args)).map_err(|e| e.to_string())?;
+        cli.options
+            .apply(Config::default())
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn definition_is_consistent() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn options_take_values_with_or_without_equals() {
+        let config = parse(&[
+            "--fps=30",
+            "-d",
+            "0.7",
+            "--palette=frost",
+            "--rune-card",
+            "--direction=left",
+            "--runes",
+            "gothic",
+            "--no-status",
+            "--background=#0a1030,#000",
+        ])
+        .unwrap();
+        assert_eq!(config.fps, 30);
+        assert_eq!(config.direction, Direction::Left);
+        assert_eq!(config.rune_set, RuneSet::Gothic);
+        assert!(!config.show_status);
+        assert_eq!(config.density, 0.7);
+        assert_eq!(config.palette, Palette::Frost);
+        assert!(config.rune_card);
+        assert_eq!(
+            config.background,
+            Some(Background::Vertical((0x0a, 0x10, 0x30), (0, 0, 0)))
+        );
+    }
+
+    #[test]
+    fn auto_palette_follows_the_last_choice() {
+        let config = parse(&["--palette", "auto", "--seasons"]).unwrap();
+        assert!(config.auto_palette && config.seasons);
+        let config = Config {
+            auto_palette: true,
+            ..Config::default()
+        };
+        let cli = Cli::try_parse_from(["runefall", "--palette", "ember"]).unwrap();
+        let config = cli.options.apply(config).unwrap();
+        assert!(!config.auto_palette);
+        assert_eq!(config.palette, Palette::Ember);
+    }
+
+    #[test]
+    fn mistakes_are_errors_not_defaults() {
+        for args in [
+            &["--pallete", "frost"][..],
+            &["--fps", "fast"],
+            &["--fps", "500"],
+            &["--density=2"],
+            &["--color-mode", "sepia"],
+            &["--ambiguous-width", "3"],
+            &["--direction", "sideways"],
+            &["--background", "navy"],
+            &["--palette", "frost", "--palette-from", "tomorrow.conf"],
+        ] {
+            assert!(parse(args).is_err(), "{args:?}");
+        }
+        assert_eq!(
+            parse(&["--palette", "plaid"]).unwrap_err(),
+            "--palette: unknown palette `plaid`"
+        );
+    }
+}
@@ -0,0 +1,372 @@
+use crossterm::style::Color;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+// ── Terminal color capabilities ───────────────────────────────────────
+
+/// How many colors the terminal can show. Palette output is quantized to fit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorMode {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+    Mono,
+}
+
+/// The 16 ANSI colors with VGA-style reference values.
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkRed, (128, 0, 0)),
+    (Color::DarkGreen, (0, 128, 0)),
+    (Color::DarkYellow, (128, 128, 0)),
+    (Color::DarkBlue, (0, 0, 128)),
+    (Color::DarkMagenta, (128, 0, 128)),
+    (Color::DarkCyan, (0, 128, 128)),
+    (Color::Grey, (192, 192, 192)),
+    (Color::DarkGrey, (128, 128, 128)),
+    (Color::Red, (255, 0, 0)),
+    (Color::Green, (0, 255, 0)),
+    (Color::Yellow, (255, 255, 0)),
+    (Color::Blue, (0, 0, 255)),
+    (Color::Magenta, (255, 0, 255)),
+    (Color::Cyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Channel levels of the xterm 6×6×6 color cube (indexes 16–231).
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl ColorMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorMode::TrueColor => "truecolor",
+            ColorMode::Ansi256 => "256",
+            ColorMode::Ansi16 => "16",
+            ColorMode::Mono => "mono",
+        }
+    }
+
+    pub fn from_name(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "truecolor" | "24bit" | "rgb" => Some(ColorMode::TrueColor),
+            "256" | "ansi256" => Some(ColorMode::Ansi256),
+            "16" | "ansi" | "ansi16" => Some(ColorMode::Ansi16),
+            "mono" | "none" | "0" => Some(ColorMode::Mono),
+            _ => None,
+        }
+    }
+
+    /// Detect the capability of the current terminal from `COLORTERM`, `TERM`
+    /// and the terminfo entry for `TERM`. A non-empty `NO_COLOR` forces
+    /// [`ColorMode::Mono`] (see <https://no-color.org>).
+    pub fn detect() -> Self {
+        if env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            return ColorMode::Mono;
+        }
+        let colorterm = env::var("COLORTERM").ok();
+        let term = env::var("TERM").ok();
+        let terminfo_colors = term.as_deref().and_then(terminfo_colors);
+        Self::from_env(colorterm.as_deref(), term.as_deref(), terminfo_colors)
+    }
+
+    fn from_env(colorterm: Option<&str>, term: Option<&str>, terminfo_colors: Option<u32>) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return ColorMode::TrueColor;
+        }
+        let Some(term) = term else {
+            return ColorMode::Ansi16;
+        };
+        if term == "dumb" {
+            return ColorMode::Mono;
+        }
+        if term.ends_with("-direct") {
+            return ColorMode::TrueColor;
+        }
+        match terminfo_colors {
+            Some(n) if n >= 1 << 24 => ColorMode::TrueColor,
+            Some(n) if n >= 256 => ColorMode::Ansi256,
+            Some(n) if n >= 8 => ColorMode::Ansi16,
+            Some(_) => ColorMode::Mono,
+            None if term.contains("256color") => ColorMode::Ansi256,
+            None => ColorMode::Ansi16,
+        }
+    }
+
+    /// Map a color onto the nearest one this mode can display. In
+    /// [`ColorMode::Mono`] everything uses the terminal's default color and the
+    /// renderer conveys intensity through attributes instead.
+    pub fn quantize(&self, color: Color) -> Color {
+        let Color::Rgb { r, g, b } = color else {
+            return color;
+        };
+        match self {
+            ColorMode::TrueColor => color,
+            ColorMode::Ansi256 => Color::AnsiValue(rgb_to_ansi256(r, g, b)),
+            ColorMode::Ansi16 => rgb_to_ansi16(r, g, b),
+            ColorMode::Mono => Color::Reset,
+        }
+    }
+}
+
+/// Parse `#rrggbb` or `#rgb` into an RGB color.
+pub fn parse_hex(s: &str) -> Option<Color> {
+    let hex = s.strip_prefix('#')?;
+    if !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize, len: usize| {
+        let value = u8::from_str_radix(&hex[i * len..(i + 1) * len], 16).ok()?;
+        Some(if len == 1 { value * 17 } else { value })
+    };
+    let len = match hex.len() {
+        6 => 2,
+        3 => 1,
+        _ => return None,
+    };
+    Some(Color::Rgb {
+        r: channel(0, len)?,
+        g: channel(1, len)?,
+        b: channel(2, len)?,
+    })
+}
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let dr = r1 as i32 - r2 as i32;
+    let dg = g1 as i32 - g2 as i32;
+    let db = b1 as i32 - b2 as i32;
+    // Weighted toward green, which the eye is most sensitive to
+    (2 * dr * dr + 4 * dg * dg + 3 * db * db) as u32
+}
+
+/// Nearest xterm-256 index, choosing between the color cube and the gray ramp.
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - c as i32).abs())
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+    let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+    // Gray ramp 232–255 runs from 8 to 238 in steps of 10
+    let avg = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = (avg.saturating_sub(3) / 10).min(23) as u8;
+    let gray_level = 8 + 10 * gray_step;
+    let gray = (gray_level, gray_level, gray_level);
+
+    if distance((r, g, b), gray) < distance((r, g, b), cube) {
+        232 + gray_step
+    } else {
+        cube_index as u8
+    }
+}
+
+/// Nearest of the 16 ANSI colors.
+pub fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+// ── terminfo lookup ───────────────────────────────────────────────────
+
+/// Index of the `colors` capability in the terminfo numbers section.
+const TERMINFO_COLORS: usize = 13;
+
+fn terminfo_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(dir) = env::var_os("TERMINFO") {
+        dirs.push(PathBuf::from(dir));
+    }
+    if let Some(home) = env::var_os("HOME") {
+        dirs.push(PathBuf::from(home).join(".terminfo"));
+    }
+    if let Ok(list) = env::var("TERMINFO_DIRS") {
+        dirs.extend(list.split(':').filter(|d| !d.is_empty()).map(PathBuf::from));
+    }
+    for dir in [
+        "/etc/terminfo",
+        "/lib/terminfo",
+        "/usr/share/terminfo",
+        "/usr/lib/terminfo",
+    ] {
+        dirs.push(PathBuf::from(dir));
+    }
+    dirs
+}
+
+/// The `colors` capability of a terminfo entry, if it can be found.
+fn terminfo_colors(term: &str) -> Option<u32> {
+    let first = term.chars().next()?;
+    terminfo_dirs().into_iter().find_map(|dir| {
+        // Linux uses the first letter as subdirectory, macOS its hex code
+        [
+            dir.join(first.to_string()).join(term),
+            dir.join(format!("{:x}", first as u32)).join(term),
+        ]
+        .iter()
+        .find_map(|path| parse_terminfo_colors(&fs::read(path).ok()?))
+    })
+}
+
+/// Read the `colors` number out of a compiled terminfo entry (legacy 16-bit
+/// or extended 32-bit number format). Entries without the capability are
+/// monochrome and report 0.
+fn parse_terminfo_colors(data: &[u8]) -> Option<u32> {
+    let short = |i: usize| -> Option<usize> {
+        Some(u16::from_le_bytes([*data.get(2 * i)?, *data.get(2 * i + 1)?]) as usize)
+    };
+    let number_size = match short(0)? {
+        0o432 => 2,
+        0o1036 => 4,
+        _ => return None,
+    };
+    let (names_size, bools_count, nums_count) = (short(1)?, short(2)?, short(3)?);
+    if TERMINFO_COLORS >= nums_count {
+        return Some(0);
+    }
+
+    let mut offset = 12 + names_size + bools_count;
+    offset += offset % 2; // numbers are aligned on an even byte
+    let at = offset + TERMINFO_COLORS * number_size;
+    let bytes = data.get(at..at + number_size)?;
+    let value = if number_size == 2 {
+        i16::from_le_bytes([bytes[0], bytes[1]]) as i32
+    } else {
+        i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    };
+    // Negative values mean the capability is absent or cancelled
+    Some(u32::try_from(value).unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_colors_parse_in_long_and_short_form() {
+        assert_eq!(
+            parse_hex("#1e90ff"),
+            Some(Color::Rgb {
+                r: 30,
+                g: 144,
+                b: 255
+            })
+        );
+        assert_eq!(
+            parse_hex("#FA0"),
+            Some(Color::Rgb {
+                r: 255,
+                g: 170,
+                b: 0
+            })
+        );
+        for bad in ["1e90ff", "#1e90f", "#ggg", "#1e90ff00", "#"] {
+            assert_eq!(parse_hex(bad), None, "{bad}");
+        }
+    }
+
+    #[test]
+    fn colorterm_truecolor_wins() {
+        let mode = ColorMode::from_env(Some("truecolor"), Some("xterm"), Some(8));
+        assert_eq!(mode, ColorMode::TrueColor);
+        let mode = ColorMode::from_env(Some("24bit"), None, None);
+        assert_eq!(mode, ColorMode::TrueColor);
+    }
+
+    #[test]
+    fn term_names_without_terminfo() {
+        let detect = |term| ColorMode::from_env(None, term, None);
+        assert_eq!(detect(Some("xterm-256color")), ColorMode::Ansi256);
+        assert_eq!(detect(Some("xterm-direct")), ColorMode::TrueColor);
+        assert_eq!(detect(Some("linux")), ColorMode::Ansi16);
+        assert_eq!(detect(Some("dumb")), ColorMode::Mono);
+        assert_eq!(detect(None), ColorMode::Ansi16);
+    }
+
+    #[test]
+    fn terminfo_colors_take_precedence_over_term_name() {
+        let detect = |colors| ColorMode::from_env(None, Some("screen"), Some(colors));
+        assert_eq!(detect(256), ColorMode::Ansi256);
+        assert_eq!(detect(8), ColorMode::Ansi16);
+        assert_eq!(detect(1 << 24), ColorMode::TrueColor);
+        assert_eq!(detect(0), ColorMode::Mono);
+    }
+
+    #[test]
+    fn parses_legacy_terminfo_numbers() {
+        // Header, "x|y\0" names, one boolean, padding, then 14 numbers
+        let mut data = Vec::new();
+        for short in [0o432u16, 4, 1, 14, 0, 0] {
+            data.extend_from_slice(&short.to_le_bytes());
+        }
+        data.extend_from_slice(b"x|y\0");
+        data.push(1);
+        data.push(0);
+        for n in 0..14 {
+            let value: i16 = if n as usize == TERMINFO_COLORS {
+                256
+            } else {
+                -1
+            };
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        assert_eq!(parse_terminfo_colors(&data), Some(256));
+    }
+
+    #[test]
+    fn parses_extended_terminfo_numbers() {
+        let mut data = Vec::new();
+        for short in [0o1036u16, 2, 0, 14, 0, 0] {
+            data.extend_from_slice(&short.to_le_bytes());
+        }
+        data.extend_from_slice(b"x\0");
+        for n in 0..14 {
+            let value: i32 = if n as usize == TERMINFO_COLORS {
+                1 << 24
+            } else {
+                -1
+            };
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        assert_eq!(parse_terminfo_colors(&data), Some(1 << 24));
+    }
+
+    #[test]
+    fn rejects_garbage_terminfo() {
+        assert_eq!(parse_terminfo_colors(b""), None);
+        assert_eq!(parse_terminfo_colors(b"not a terminfo file"), None);
+    }
+
+    #[test]
+    fn ansi256_cube_and_gray_ramp() {
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(95, 135, 175), 16 + 36 + 2 * 6 + 3);
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+        assert_eq!(rgb_to_ansi256(238, 238, 238), 255);
+    }
+
+    #[test]
+    fn ansi16_nearest() {
+        assert_eq!(rgb_to_ansi16(250, 10, 10), Color::Red);
+        assert_eq!(rgb_to_ansi16(20, 120, 30), Color::DarkGreen);
+        assert_eq!(rgb_to_ansi16(40, 10, 110), Color::DarkBlue);
+        assert_eq!(rgb_to_ansi16(5, 5, 5), Color::Black);
+    }
+
+    #[test]
+    fn quantize_by_mode() {
+        let color = Color::Rgb { r: 255, g: 0, b: 0 };
+        assert_eq!(ColorMode::TrueColor.quantize(color), color);
+        assert_eq!(ColorMode::Ansi256.quantize(color), Color::AnsiValue(196));
+        assert_eq!(ColorMode::Ansi16.quantize(color), Color::Red);
+        assert_eq!(ColorMode::Mono.quantize(color), Color::Reset);
+        assert_eq!(ColorMode::Ansi16.quantize(Color::Reset), Color::Reset);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::color::ColorMode;
+use crate::direction::Direction;
+use crate::keys::KeyMap;
+use crate::palette::{Background, Palette};
+use crate::runes::RuneSet;
+use std::io;
+
+// ── Startup configuration ─────────────────────────────────────────────
+
+/// Initial settings for a [`Renderer`](crate::Renderer).
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub palette: Palette,
+    /// User-defined palettes from the config file.
+    pub custom_palettes: Vec<Palette>,
+    /// Background behind every palette, in place of their own; `None` keeps
+    /// each palette's background, if it has one.
+    pub background: Option<Background>,
+    /// Follow the local time of day instead of `palette`.
+    pub auto_palette: bool,
+    /// Tint the time-of-day palette around solstices and equinoxes.
+    pub seasons: bool,
+    pub fps: u64,
+    pub density: f32,
+    /// Rune set to start with.
+    pub rune_set: RuneSet,
+    /// User-defined sets, cycled through after the built-in ones.
+    pub custom_rune_sets: Vec<RuneSet>,
+    pub direction: Direction,
+    /// Frames over which palette and rune set switches blend in; 0 switches
+    /// at once.
+    pub transition: u64,
+    /// Show the status bar at startup; it can still be toggled.
+    pub show_status: bool,
+    /// Seed for the random generator; `None` picks a fresh one per run.
+    pub seed: Option<u64>,
+    /// Colors the terminal can display; palette output is quantized to fit.
+    pub color_mode: ColorMode,
+    /// Cells taken by glyphs of ambiguous width (1 or 2), matching how the
+    /// terminal's font draws them.
+    pub ambiguous_width: u8,
+    /// Show ASCII stand-ins instead of runes, for fonts without runic glyphs.
+    pub ascii: bool,
+    /// Phrase to reveal in the middle of the screen, transliterated into the
+    /// current rune set.
+    pub message: Option<String>,
+    /// Periodically highlight one rune on screen with a card naming it.
+    pub rune_card: bool,
+    /// Key bindings for the interactive controls.
+    pub keys: KeyMap,
+    /// Profile the save-profile key writes to; `None` saves to `default`.
+    pub profile: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            palette: Palette::Arcane,
+            custom_palettes: Vec::new(),
+            background: None,
+            auto_palette: false,
+            seasons: false,
+            fps: 20,
+            density: 0.4,
+            rune_set: RuneSet::All,
+            custom_rune_sets: Vec::new(),
+            direction: Direction::Down,
+            transition: 20,
+            show_status: true,
+            seed: None,
+            color_mode: ColorMode::TrueColor,
+            ambiguous_width: 1,
+            ascii: false,
+            message: None,
+            rune_card: false,
+            keys: KeyMap::default(),
+            profile: None,
+        }
+    }
+}
+
+impl Config {
+    /// A built-in palette by name or alias, or a custom one by its name.
+    pub fn find_palette(&self, name: &str) -> Option<Palette> {
+        Palette::from_name(name).or_else(|| {
+            self.custom_palettes
+                .iter()
+                .find(|palette| palette.name().eq_ignore_ascii_case(name))
+                .cloned()
+        })
+    }
+
+    /// Start with palette `name`, or with the time-of-day palette for
+    /// `auto`. `false` if there is no such palette.
+    pub fn select_palette(&mut self, name: &str) -> bool {
+        if name.eq_ignore_ascii_case("auto") {
+            self.auto_palette = true;
+            return true;
+        }
+        let Some(palette) = self.find_palette(name) else {
+            return false;
+        };
+        self.palette = palette;
+        self.auto_palette = false;
+        true
+    }
+
+    /// A custom rune set by its name, or else whatever
+    /// [`RuneSet::from_spec`] makes of `spec`.
+    pub fn find_rune_set(&self, spec: &str) -> io::Result<RuneSet> {
+        match self
+            .custom_rune_sets
+            .iter()
+            .find(|set| set.name().eq_ignore_ascii_case(spec))
+        {
+            Some(set) => Ok(set.clone()),
+            None => RuneSet::from_spec(spec),
+        }
+    }
+}
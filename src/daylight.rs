@@ -0,0 +1,226 @@
+use crate::oklab::mix;
+use crate::palette::Palette;
+use chrono::{Datelike, Local, Timelike};
+use crossterm::style::Color;
+
+// ── Time-of-day palette ───────────────────────────────────────────────
+//
+// Dawn glows in Ember, the day is Frost, dusk turns Arcane and the night is
+// a deep Emerald. Between those hours the colors blend through OKLab, so the
+// screen drifts from one look to the next without ever visibly switching.
+
+type Rgb = (u8, u8, u8);
+
+/// Trail colors sampled at evenly spaced positions, tail first.
+const SAMPLES: usize = 5;
+
+/// The colors a palette is rebuilt from: its head and its sampled trail.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Look {
+    head: Rgb,
+    trail: [Rgb; SAMPLES],
+}
+
+fn rgb(color: Color) -> Rgb {
+    match color {
+        Color::Rgb { r, g, b } => (r, g, b),
+        _ => (0, 0, 0),
+    }
+}
+
+impl Look {
+    fn of(palette: &Palette) -> Look {
+        Look {
+            head: rgb(palette.head_color(0, 0, 0)),
+            trail: std::array::from_fn(|idx| {
+                rgb(palette.color(idx as f32 / (SAMPLES - 1) as f32, 0, 0, 0))
+            }),
+        }
+    }
+
+    /// A straight ramp from `tail` to `tip` under `head`.
+    fn ramp(head: Rgb, tail: Rgb, tip: Rgb) -> Look {
+        Look {
+            head,
+            trail: std::array::from_fn(|idx| mix(tail, tip, idx as f32 / (SAMPLES - 1) as f32)),
+        }
+    }
+
+    /// This look darkened toward black by `amount`.
+    fn deepen(self, amount: f32) -> Look {
+        Look {
+            head: mix(self.head, (0, 0, 0), amount / 2.0),
+            trail: self.trail.map(|color| mix(color, (0, 0, 0), amount)),
+        }
+    }
+
+    fn blend(self, other: Look, t: f32) -> Look {
+        Look {
+            head: mix(self.head, other.head, t),
+            trail: std::array::from_fn(|idx| mix(self.trail[idx], other.trail[idx], t)),
+        }
+    }
+
+    fn palette(&self, name: &str) -> Palette {
+        let color = |(r, g, b): Rgb| Color::Rgb { r, g, b };
+        let stops: Vec<(f32, Color)> = self
+            .trail
+            .iter()
+            .enumerate()
+            .map(|(idx, &c)| (idx as f32 / (SAMPLES - 1) as f32, color(c)))
+            .collect();
+        Palette::gradient(name, color(self.head), &stops)
+            .expect("sampled stops are RGB and within 0.0-1.0")
+    }
+}
+
+/// Smooth start and end for a blend, so looks ease into each other.
+fn smoothstep(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Times of day, every six hours from midnight, with the look each one has.
+const PHASES: [&str; 4] = ["Night", "Dawn", "Day", "Dusk"];
+
+fn phase_look(phase: usize) -> Look {
+    match phase % PHASES.len() {
+        0 => Look::of(&Palette::Emerald).deepen(0.45),
+        1 => Look::of(&Palette::Ember),
+        2 => Look::of(&Palette::Frost),
+        _ => Look::of(&Palette::Arcane),
+    }
+}
+
+/// Solstices and equinoxes by day of the year (0-based, northern calendar),
+/// with their looks as head, tail and tip colors.
+const SEASONS: [(&str, u16, Rgb, Rgb, Rgb); 4] = [
+    ("Ostara", 78, (255, 230, 240), (20, 40, 20), (240, 130, 180)),
+    (
+        "Midsummer",
+        171,
+        (255, 245, 200),
+        (50, 30, 0),
+        (255, 200, 60),
+    ),
+    ("Mabon", 264, (255, 215, 160), (40, 15, 0), (220, 110, 30)),
+    ("Yule", 354, (240, 248, 255), (20, 24, 40), (185, 205, 235)),
+];
+
+/// Days either side of a solstice or equinox that its look shows through.
+const SEASON_DAYS: f32 = 21.0;
+
+/// Palette that follows the local time of day and, with `seasons`, tints
+/// toward a solstice or equinox look in the days around each.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Daylight {
+    pub seasons: bool,
+}
+
+impl Daylight {
+    /// The palette for the current local time.
+    pub fn now(&self) -> Palette {
+        let now = Local::now();
+        let hour = now.hour() as f32 + now.minute() as f32 / 60.0 + now.second() as f32 / 3600.0;
+        self.palette_at(now.ordinal0() as f32 + hour / 24.0, hour)
+    }
+
+    /// The palette on day `day` of the year (0-based, fractions counting
+    /// toward the next day) at `hour`, 0.0-24.0, named after the nearest time
+    /// of day, e.g. `Auto: Dusk`.
+    pub fn palette_at(&self, day: f32, hour: f32) -> Palette {
+        let hour = hour.rem_euclid(24.0);
+        let span = 24.0 / PHASES.len() as f32;
+        let phase = (hour / span) as usize;
+        let t = smoothstep(hour / span - phase as f32);
+        let mut look = phase_look(phase).blend(phase_look(phase + 1), t);
+        let mut name = format!(
+            "Auto: {}",
+            PHASES[(hour / span).round() as usize % PHASES.len()]
+        );
+
+        if self.seasons {
+            for (season, date, head, tail, tip) in SEASONS {
+                let away = (day - date as f32).rem_euclid(365.0);
+                let days = away.min(365.0 - away);
+                if days < SEASON_DAYS {
+                    let weight = 0.6 * smoothstep(1.0 - days / SEASON_DAYS);
+                    look = look.blend(Look::ramp(head, tail, tip), weight);
+                    name = format!("{name}, {season}");
+                }
+            }
+        }
+        look.palette(&name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn look(palette: &Palette) -> Look {
+        Look::of(palette)
+    }
+
+    #[test]
+    fn each_phase_shows_its_palette() {
+        let daylight = Daylight::default();
+        assert_eq!(
+            look(&daylight.palette_at(100.0, 6.0)),
+            look(&Palette::Ember)
+        );
+        assert_eq!(
+            look(&daylight.palette_at(100.0, 12.0)),
+            look(&Palette::Frost)
+        );
+        assert_eq!(
+            look(&daylight.palette_at(100.0, 18.0)),
+            look(&Palette::Arcane)
+        );
+        assert_eq!(daylight.palette_at(100.0, 17.0).name(), "Auto: Dusk");
+        assert_eq!(daylight.palette_at(100.0, 23.9).name(), "Auto: Night");
+    }
+
+    #[test]
+    fn colors_drift_without_jumps() {
+        let daylight = Daylight { seasons: true };
+        let step = |a: Rgb, b: Rgb| {
+            (a.0.abs_diff(b.0))
+                .max(a.1.abs_diff(b.1))
+                .max(a.2.abs_diff(b.2))
+        };
+        // A day in 5-minute steps, then a year in quarter days
+        let day = (0..=24 * 12).map(|minutes| (0.0, minutes as f32 / 12.0));
+        let year = (0..=365 * 4).map(|quarter| (quarter as f32 / 4.0, 12.0));
+        for times in [day.collect::<Vec<_>>(), year.collect()] {
+            let looks: Vec<Look> = times
+                .iter()
+                .map(|&(day, hour)| look(&daylight.palette_at(day, hour)))
+                .collect();
+            for (pair, (day, hour)) in looks.windows(2).zip(&times[1..]) {
+                let (last, next) = (pair[0], pair[1]);
+                for (a, b) in [(last.head, next.head)]
+                    .into_iter()
+                    .chain(last.trail.into_iter().zip(next.trail))
+                {
+                    assert!(step(a, b) <= 8, "day {day} {hour}: {a:?} -> {b:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn seasons_tint_only_around_their_dates() {
+        let plain = Daylight::default();
+        let seasonal = Daylight { seasons: true };
+        assert_eq!(seasonal.palette_at(354.0, 12.0).name(), "Auto: Day, Yule");
+        assert_ne!(
+            look(&seasonal.palette_at(354.0, 12.0)),
+            look(&plain.palette_at(354.0, 12.0))
+        );
+        assert_eq!(
+            look(&seasonal.palette_at(210.0, 12.0)),
+            look(&plain.palette_at(210.0, 12.0))
+        );
+    }
+}
@@ -0,0 +1,128 @@
+// ── Scroll direction ──────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Down,
+    Up,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Direction::Down => "down",
+            Direction::Up => "up",
+            Direction::Left => "left",
+            Direction::Right => "right",
+        }
+    }
+
+    pub fn from_name(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "down" => Some(Direction::Down),
+            "up" => Some(Direction::Up),
+            "left" => Some(Direction::Left),
+            "right" => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    pub fn max_lanes(&self, cols: u16, rows: u16) -> u16 {
+        match self {
+            Direction::Down | Direction::Up => cols,
+            Direction::Left | Direction::Right => rows,
+        }
+    }
+
+    pub fn max_pos(&self, cols: u16, rows: u16) -> u16 {
+        match self {
+            Direction::Down | Direction::Up => rows,
+            Direction::Left | Direction::Right => cols,
+        }
+    }
+
+    // Convert abstract (lane, pos) to screen (x, y)
+    pub fn to_screen(&self, lane: u16, pos: i32, cols: u16, rows: u16) -> Option<(u16, u16)> {
+        match self {
+            Direction::Down => {
+                if pos >= 0 && pos < rows as i32 {
+                    Some((lane, pos as u16))
+                } else {
+                    None
+                }
+            }
+            Direction::Up => {
+                if pos >= 0 && pos < rows as i32 {
+                    Some((lane, rows.saturating_sub(1).saturating_sub(pos as u16)))
+                } else {
+                    None
+                }
+            }
+            Direction::Right => {
+                if pos >= 0 && pos < cols as i32 {
+                    Some((pos as u16, lane))
+                } else {
+                    None
+                }
+            }
+            Direction::Left => {
+                if pos >= 0 && pos < cols as i32 {
+                    Some((cols.saturating_sub(1).saturating_sub(pos as u16), lane))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Direction; 4] = [
+        Direction::Down,
+        Direction::Up,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    #[test]
+    fn positions_outside_the_screen_are_hidden() {
+        for dir in ALL {
+            let max_pos = dir.max_pos(10, 5) as i32;
+            assert_eq!(dir.to_screen(0, -1, 10, 5), None, "{dir:?}");
+            assert_eq!(dir.to_screen(0, max_pos, 10, 5), None, "{dir:?}");
+            assert!(dir.to_screen(0, 0, 10, 5).is_some(), "{dir:?}");
+            assert!(dir.to_screen(0, max_pos - 1, 10, 5).is_some(), "{dir:?}");
+        }
+    }
+
+    #[test]
+    fn every_lane_and_position_lands_on_screen() {
+        for dir in ALL {
+            for lane in 0..dir.max_lanes(10, 5) {
+                for pos in 0..dir.max_pos(10, 5) as i32 {
+                    let (x, y) = dir.to_screen(lane, pos, 10, 5).unwrap();
+                    assert!(x < 10 && y < 5, "{dir:?} lane {lane} pos {pos}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn streams_enter_from_the_named_edge() {
+        assert_eq!(Direction::Down.to_screen(3, 0, 10, 5), Some((3, 0)));
+        assert_eq!(Direction::Up.to_screen(3, 0, 10, 5), Some((3, 4)));
+        assert_eq!(Direction::Right.to_screen(3, 0, 10, 5), Some((0, 3)));
+        assert_eq!(Direction::Left.to_screen(3, 0, 10, 5), Some((9, 3)));
+    }
+
+    #[test]
+    fn empty_screen_shows_nothing() {
+        for dir in ALL {
+            assert_eq!(dir.to_screen(0, 0, 0, 0), None, "{dir:?}");
+        }
+    }
+}
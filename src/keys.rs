@@ -0,0 +1,202 @@
+use crate::direction::Direction;
+use crate::palette::Palette;
+use crate::runes::RuneSet;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+// ── Key bindings ──────────────────────────────────────────────────────
+
+/// Something a key does while the screensaver runs.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Action {
+    Quit,
+    Faster,
+    Slower,
+    Denser,
+    Sparser,
+    Palette(Palette),
+    CyclePalette,
+    RuneSet(RuneSet),
+    CycleRuneSet,
+    Direction(Direction),
+    ToggleStatus,
+    ToggleCard,
+    SaveProfile,
+}
+
+impl Action {
+    /// Parse an action name as used in the `[keys]` table of the config file,
+    /// e.g. `quit`, `palette-frost`, `runes-gothic` or `direction-up`.
+    pub fn from_name(s: &str) -> Option<Self> {
+        let s = s.to_lowercase();
+        if let Some(palette) = s.strip_prefix("palette-") {
+            return Palette::from_name(palette).map(Action::Palette);
+        }
+        if let Some(set) = s.strip_prefix("runes-") {
+            return RuneSet::from_name(set).map(Action::RuneSet);
+        }
+        if let Some(direction) = s.strip_prefix("direction-") {
+            return Direction::from_name(direction).map(Action::Direction);
+        }
+        match s.as_str() {
+            "quit" => Some(Action::Quit),
+            "faster" => Some(Action::Faster),
+            "slower" => Some(Action::Slower),
+            "denser" => Some(Action::Denser),
+            "sparser" => Some(Action::Sparser),
+            "cycle-palettes" => Some(Action::CyclePalette),
+            "cycle-runes" => Some(Action::CycleRuneSet),
+            "toggle-status" => Some(Action::ToggleStatus),
+            "toggle-card" => Some(Action::ToggleCard),
+            "save-profile" => Some(Action::SaveProfile),
+            _ => None,
+        }
+    }
+}
+
+/// Parse a key: a single character, or one of `esc`, `enter`, `tab`,
+/// `space`, `backspace`, `up`, `down`, `left` and `right`.
+pub fn parse_key(s: &str) -> Option<KeyCode> {
+    let mut chars = s.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(ch));
+    }
+    match s.to_lowercase().as_str() {
+        "esc" | "escape" => Some(KeyCode::Esc),
+        "enter" | "return" => Some(KeyCode::Enter),
+        "tab" => Some(KeyCode::Tab),
+        "space" => Some(KeyCode::Char(' ')),
+        "backspace" => Some(KeyCode::Backspace),
+        "up" => Some(KeyCode::Up),
+        "down" => Some(KeyCode::Down),
+        "left" => Some(KeyCode::Left),
+        "right" => Some(KeyCode::Right),
+        _ => None,
+    }
+}
+
+/// Which key triggers which action.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyMap {
+    bindings: Vec<(KeyCode, Action)>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        let char_key = |ch: char, action: Action| (KeyCode::Char(ch), action);
+        KeyMap {
+            bindings: vec![
+                char_key('q', Action::Quit),
+                char_key('Q', Action::Quit),
+                (KeyCode::Esc, Action::Quit),
+                char_key('+', Action::Faster),
+                char_key('=', Action::Faster),
+                char_key('-', Action::Slower),
+                char_key('[', Action::Sparser),
+                char_key(']', Action::Denser),
+                char_key('1', Action::Palette(Palette::Arcane)),
+                char_key('2', Action::Palette(Palette::Emerald)),
+                char_key('3', Action::Palette(Palette::Frost)),
+                char_key('4', Action::Palette(Palette::Ember)),
+                char_key('5', Action::Palette(Palette::Rainbow)),
+                char_key('0', Action::Palette(Palette::BlinkingRainbow)),
+                char_key('c', Action::CyclePalette),
+                char_key('a', Action::RuneSet(RuneSet::All)),
+                char_key('e', Action::RuneSet(RuneSet::Elder)),
+                char_key('y', Action::RuneSet(RuneSet::Younger)),
+                char_key('s', Action::RuneSet(RuneSet::Anglo)),
+                char_key('o', Action::RuneSet(RuneSet::Ogham)),
+                char_key('m', Action::RuneSet(RuneSet::Mystic)),
+                char_key('r', Action::CycleRuneSet),
+                (KeyCode::Up, Action::Direction(Direction::Up)),
+                (KeyCode::Down, Action::Direction(Direction::Down)),
+                (KeyCode::Left, Action::Direction(Direction::Left)),
+                (KeyCode::Right, Action::Direction(Direction::Right)),
+                char_key('i', Action::ToggleStatus),
+                char_key('l', Action::ToggleCard),
+                char_key('p', Action::SaveProfile),
+            ],
+        }
+    }
+}
+
+impl KeyMap {
+    /// The action bound to `key`, if any.
+    pub fn action(&self, key: KeyCode) -> Option<&Action> {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == key)
+            .map(|(_, action)| action)
+    }
+
+    /// The action for a key press. Ctrl+C always quits, whatever is bound to
+    /// `c`, so there is always a way out.
+    pub fn action_for(&self, event: &KeyEvent) -> Option<&Action> {
+        static QUIT: Action = Action::Quit;
+        if event.code == KeyCode::Char('c') && event.modifiers.contains(KeyModifiers::CONTROL) {
+            return Some(&QUIT);
+        }
+        self.action(event.code)
+    }
+
+    /// Bind `action` to exactly `keys`, taking them away from other actions.
+    pub fn bind(&mut self, action: Action, keys: &[KeyCode]) {
+        self.bindings
+            .retain(|(key, bound)| *bound != action && !keys.contains(key));
+        self.bindings
+            .extend(keys.iter().map(|&key| (key, action.clone())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebinding_moves_keys_between_actions() {
+        let mut keys = KeyMap::default();
+        keys.bind(Action::Quit, &[KeyCode::Char('x'), KeyCode::Char('e')]);
+        assert_eq!(keys.action(KeyCode::Char('x')), Some(&Action::Quit));
+        assert_eq!(keys.action(KeyCode::Char('e')), Some(&Action::Quit));
+        assert_eq!(keys.action(KeyCode::Char('q')), None);
+        assert_eq!(keys.action(KeyCode::Esc), None);
+        assert_eq!(
+            keys.action(KeyCode::Char('y')),
+            Some(&Action::RuneSet(RuneSet::Younger))
+        );
+    }
+
+    #[test]
+    fn ctrl_c_quits_whatever_is_bound() {
+        let mut keys = KeyMap::default();
+        keys.bind(Action::ToggleCard, &[KeyCode::Char('c')]);
+        let press = |modifiers| KeyEvent::new(KeyCode::Char('c'), modifiers);
+        assert_eq!(
+            keys.action_for(&press(KeyModifiers::CONTROL)),
+            Some(&Action::Quit)
+        );
+        assert_eq!(
+            keys.action_for(&press(KeyModifiers::NONE)),
+            Some(&Action::ToggleCard)
+        );
+        assert_eq!(
+            KeyMap::default().action_for(&press(KeyModifiers::NONE)),
+            Some(&Action::CyclePalette)
+        );
+    }
+
+    #[test]
+    fn actions_and_keys_parse_by_name() {
+        assert_eq!(
+            Action::from_name("palette-frost"),
+            Some(Action::Palette(Palette::Frost))
+        );
+        assert_eq!(
+            Action::from_name("runes-gothic"),
+            Some(Action::RuneSet(RuneSet::Gothic))
+        );
+        assert_eq!(Action::from_name("palette-plaid"), None);
+        assert_eq!(parse_key("Esc"), Some(KeyCode::Esc));
+        assert_eq!(parse_key("ᚠ"), Some(KeyCode::Char('ᚠ')));
+        assert_eq!(parse_key("ctrl-x"), None);
+    }
+}
@@ -0,0 +1,45 @@
+//! Runic rain simulation behind the `runefall` screensaver.
+//!
+//! Create a [`Renderer`] for a given screen size, call [`Renderer::tick`] once
+//! per frame, and either compose the frame with [`Renderer::draw`] and read it
+//! back from [`Renderer::buffer`], or let [`Renderer::render`] send the
+//! changed cells to a [`Backend`] — a terminal via [`CrosstermBackend`], or an
+//! in-memory grid via [`MemoryBackend`].
+
+mod backend;
+mod buffer;
+mod color;
+mod config;
+mod daylight;
+mod direction;
+mod keys;
+mod lore;
+mod message;
+mod oklab;
+mod palette;
+mod renderer;
+mod runes;
+mod settings;
+mod stream;
+mod theme;
+mod translit;
+mod width;
+
+pub use backend::{Backend, CrosstermBackend, MemoryBackend};
+pub use buffer::{Buffer, Cell};
+pub use color::{parse_hex, rgb_to_ansi16, rgb_to_ansi256, ColorMode};
+pub use config::Config;
+pub use daylight::Daylight;
+pub use direction::Direction;
+pub use keys::{parse_key, Action, KeyMap};
+pub use lore::{rune_info, RuneInfo};
+pub use oklab::{mix, Oklab, Oklch};
+pub use palette::{hsl_to_rgb, Background, CustomPalette, Palette};
+pub use renderer::Renderer;
+pub use runes::{
+    ascii_fallback, random_rune, RuneSet, ANGLO_SAXON, ELDER_FUTHARK, GOTHIC, MEDIEVAL, MYSTIC,
+    OGHAM, OLD_HUNGARIAN, OLD_ITALIC, OLD_TURKIC, TIFINAGH, YOUNGER_FUTHARK,
+};
+pub use settings::{config_dir, profile_path, profile_toml, save_profile};
+pub use translit::transliterate;
+pub use width::{glyph_width, is_ambiguous, str_width};
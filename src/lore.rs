@@ -0,0 +1,568 @@
+use crate::runes::RuneSet;
+
+// ── Rune names and sound values ───────────────────────────────────────
+
+/// Traditional name, sound value and meaning of a glyph within one set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RuneInfo {
+    pub glyph: char,
+    pub name: &'static str,
+    /// Sound value, roughly in IPA; empty for symbols.
+    pub sound: &'static str,
+    pub meaning: &'static str,
+}
+
+impl RuneInfo {
+    const fn new(
+        glyph: char,
+        name: &'static str,
+        sound: &'static str,
+        meaning: &'static str,
+    ) -> Self {
+        RuneInfo {
+            glyph,
+            name,
+            sound,
+            meaning,
+        }
+    }
+
+    /// Built-in sets the glyph belongs to, in cycle order, leaving out `All`.
+    pub fn sets(&self) -> Vec<RuneSet> {
+        RuneSet::BUILTIN
+            .into_iter()
+            .filter(|set| *set != RuneSet::All && set.contains(self.glyph))
+            .collect()
+    }
+}
+
+const ELDER_INFO: &[RuneInfo] = &[
+    RuneInfo::new('ᚠ', "fehu", "f", "cattle, wealth"),
+    RuneInfo::new('ᚢ', "ūruz", "u", "aurochs, strength"),
+    RuneInfo::new('ᚦ', "þurisaz", "θ", "giant, thorn"),
+    RuneInfo::new('ᚨ', "ansuz", "a", "god of the Æsir"),
+    RuneInfo::new('ᚱ', "raidō", "r", "ride, journey"),
+    RuneInfo::new('ᚲ', "kaunan", "k", "ulcer, torch"),
+    RuneInfo::new('ᚷ', "gebō", "g", "gift"),
+    RuneInfo::new('ᚹ', "wunjō", "w", "joy"),
+    RuneInfo::new('ᚺ', "hagalaz", "h", "hail"),
+    RuneInfo::new('ᚾ', "naudiz", "n", "need"),
+    RuneInfo::new('ᛁ', "īsaz", "i", "ice"),
+    RuneInfo::new('ᛃ', "jēra", "j", "year, harvest"),
+    RuneInfo::new('ᛇ', "eihwaz", "ï", "yew"),
+    RuneInfo::new('ᛈ', "perþ", "p", "unknown, perhaps a pear tree"),
+    RuneInfo::new('ᛉ', "algiz", "z", "elk, protection"),
+    RuneInfo::new('ᛊ', "sōwilō", "s", "sun"),
+    RuneInfo::new('ᛋ', "sōwilō", "s", "sun"),
+    RuneInfo::new('ᛏ', "tīwaz", "t", "the god Týr"),
+    RuneInfo::new('ᛒ', "berkanan", "b", "birch"),
+    RuneInfo::new('ᛖ', "ehwaz", "e", "horse"),
+    RuneInfo::new('ᛗ', "mannaz", "m", "man"),
+    RuneInfo::new('ᛚ', "laguz", "l", "water, lake"),
+    RuneInfo::new('ᛜ', "ingwaz", "ŋ", "the god Ing"),
+    RuneInfo::new('ᛝ', "ingwaz", "ŋ", "the god Ing"),
+    RuneInfo::new('ᛞ', "dagaz", "d", "day"),
+    RuneInfo::new('ᛟ', "ōþala", "o", "heritage, estate"),
+];
+
+const YOUNGER_INFO: &[RuneInfo] = &[
+    RuneInfo::new('ᚠ', "fé", "f", "wealth"),
+    RuneInfo::new('ᚢ', "úr", "u", "slag, drizzle"),
+    RuneInfo::new('ᚦ', "þurs", "θ", "giant"),
+    RuneInfo::new('ᚬ', "áss", "ã", "god"),
+    RuneInfo::new('ᚱ', "reið", "r", "ride"),
+    RuneInfo::new('ᚴ', "kaun", "k", "ulcer"),
+    RuneInfo::new('ᚼ', "hagall", "h", "hail"),
+    RuneInfo::new('ᚾ', "nauðr", "n", "need"),
+    RuneInfo::new('ᛁ', "ísa", "i", "ice"),
+    RuneInfo::new('ᛅ', "ár", "a", "plenty"),
+    RuneInfo::new('ᛋ', "sól", "s", "sun"),
+    RuneInfo::new('ᛏ', "týr", "t", "the god Týr"),
+    RuneInfo::new('ᛒ', "bjarkan", "b", "birch"),
+    RuneInfo::new('ᛘ', "maðr", "m", "man"),
+    RuneInfo::new('ᛚ', "lögr", "l", "water"),
+    RuneInfo::new('ᛦ', "yr", "ʀ", "yew"),
+];
+
+/// Includes the letters only used when spelling text (āc, æsc, ȳr, ēar,
+/// cweorð and dæg).
+const ANGLO_INFO: &[RuneInfo] = &[
+    RuneInfo::new('ᚠ', "feoh", "f", "wealth"),
+    RuneInfo::new('ᚢ', "ūr", "u", "aurochs"),
+    RuneInfo::new('ᚦ', "þorn", "θ", "thorn"),
+    RuneInfo::new('ᚩ', "ōs", "o", "god, mouth"),
+    RuneInfo::new('ᚱ', "rād", "r", "ride"),
+    RuneInfo::new('ᚳ', "cēn", "tʃ", "torch"),
+    RuneInfo::new('ᚷ', "gyfu", "g", "gift"),
+    RuneInfo::new('ᚹ', "wynn", "w", "joy"),
+    RuneInfo::new('ᚻ', "hægl", "h", "hail"),
+    RuneInfo::new('ᚾ', "nȳd", "n", "need"),
+    RuneInfo::new('ᛁ', "īs", "i", "ice"),
+    RuneInfo::new('ᛄ', "gēr", "j", "year"),
+    RuneInfo::new('ᛇ', "ēoh", "eo", "yew"),
+    RuneInfo::new('ᛈ', "peorð", "p", "unknown, perhaps a game piece"),
+    RuneInfo::new('ᛉ', "eolhx", "x", "elk-sedge"),
+    RuneInfo::new('ᛋ', "sigel", "s", "sun"),
+    RuneInfo::new('ᛏ', "tīr", "t", "a guiding star"),
+    RuneInfo::new('ᛒ', "beorc", "b", "birch"),
+    RuneInfo::new('ᛖ', "eh", "e", "horse"),
+    RuneInfo::new('ᛗ', "mann", "m", "man"),
+    RuneInfo::new('ᛚ', "lagu", "l", "lake, sea"),
+    RuneInfo::new('ᛝ', "ing", "ŋ", "the hero Ing"),
+    RuneInfo::new('ᛟ', "ēþel", "œ", "homeland"),
+    RuneInfo::new('ᛡ', "ior", "io", "beaver, eel"),
+    RuneInfo::new('ᛣ', "calc", "k", "chalk, cup"),
+    RuneInfo::new('ᛥ', "stān", "st", "stone"),
+    RuneInfo::new('ᛞ', "dæg", "d", "day"),
+    RuneInfo::new('ᚪ', "āc", "a", "oak"),
+    RuneInfo::new('ᚫ', "æsc", "æ", "ash tree"),
+    RuneInfo::new('ᚣ', "ȳr", "y", "bow"),
+    RuneInfo::new('ᛠ', "ēar", "ea", "earth, grave"),
+    RuneInfo::new('ᛢ', "cweorð", "kw", "unknown, perhaps fire"),
+];
+
+const MEDIEVAL_INFO: &[RuneInfo] = &[
+    RuneInfo::new('ᚠ', "fé", "f", "wealth"),
+    RuneInfo::new('ᚡ', "dotted fé", "v", "wealth"),
+    RuneInfo::new('ᚢ', "úr", "u", "slag, drizzle"),
+    RuneInfo::new('ᚤ', "dotted úr", "y", "slag, drizzle"),
+    RuneInfo::new('ᚦ', "þurs", "θ", "giant"),
+    RuneInfo::new('ᚧ', "dotted þurs", "ð", "giant"),
+    RuneInfo::new('ᚮ', "óss", "o", "estuary"),
+    RuneInfo::new('ᚯ', "dotted óss", "ø", "estuary"),
+    RuneInfo::new('ᚱ', "reið", "r", "ride"),
+    RuneInfo::new('ᚴ', "kaun", "k", "ulcer"),
+    RuneInfo::new('ᚵ', "dotted kaun", "g", "ulcer"),
+    RuneInfo::new('ᚼ', "hagall", "h", "hail"),
+    RuneInfo::new('ᚽ', "short-twig hagall", "h", "hail"),
+    RuneInfo::new('ᚿ', "short-twig nauðr", "n", "need"),
+    RuneInfo::new('ᛀ', "dotted nauðr", "n", "need"),
+    RuneInfo::new('ᛁ', "íss", "i", "ice"),
+    RuneInfo::new('ᛂ', "dotted íss", "e", "ice"),
+    RuneInfo::new('ᛆ', "short-twig ár", "a", "plenty"),
+    RuneInfo::new('ᛌ', "short-twig sól", "s", "sun"),
+    RuneInfo::new('ᛍ', "dotted sól", "c", "sun"),
+    RuneInfo::new('ᛎ', "z", "z", "sun"),
+    RuneInfo::new('ᛐ', "short-twig týr", "t", "the god Týr"),
+    RuneInfo::new('ᛑ', "dotted týr", "d", "the god Týr"),
+    RuneInfo::new('ᛒ', "bjarkan", "b", "birch"),
+    RuneInfo::new('ᛓ', "short-twig bjarkan", "b", "birch"),
+    RuneInfo::new('ᛔ', "dotted bjarkan", "p", "birch"),
+    RuneInfo::new('ᛕ', "open p", "p", "birch"),
+    RuneInfo::new('ᛘ', "maðr", "m", "man"),
+    RuneInfo::new('ᛙ', "short-twig maðr", "m", "man"),
+    RuneInfo::new('ᛚ', "lögr", "l", "water"),
+    RuneInfo::new('ᛛ', "dotted lögr", "l", "water"),
+    RuneInfo::new('ᛦ', "yr", "y", "yew"),
+    RuneInfo::new('ᛩ', "q", "kw", "a Latin letter in runes"),
+    RuneInfo::new('ᛪ', "x", "ks", "a Latin letter in runes"),
+];
+
+const OGHAM_INFO: &[RuneInfo] = &[
+    RuneInfo::new('ᚁ', "beith", "b", "birch"),
+    RuneInfo::new('ᚂ', "luis", "l", "rowan, flame"),
+    RuneInfo::new('ᚃ', "fearn", "w", "alder"),
+    RuneInfo::new('ᚄ', "sail", "s", "willow"),
+    RuneInfo::new('ᚅ', "nion", "n", "ash tree"),
+    RuneInfo::new('ᚆ', "uath", "h", "hawthorn, fear"),
+    RuneInfo::new('ᚇ', "dair", "d", "oak"),
+    RuneInfo::new('ᚈ', "tinne", "t", "holly, metal bar"),
+    RuneInfo::new('ᚉ', "coll", "k", "hazel"),
+    RuneInfo::new('ᚊ', "ceirt", "kʷ", "apple tree, rag"),
+    RuneInfo::new('ᚋ', "muin", "m", "vine, neck"),
+    RuneInfo::new('ᚌ', "gort", "g", "ivy, field"),
+    RuneInfo::new('ᚍ', "ngéadal", "gʷ", "broom, reed"),
+    RuneInfo::new('ᚎ', "straif", "st", "blackthorn, sulphur"),
+    RuneInfo::new('ᚏ', "ruis", "r", "elder tree, redness"),
+    RuneInfo::new('ᚐ', "ailm", "a", "pine"),
+    RuneInfo::new('ᚑ', "onn", "o", "gorse, ash tree"),
+    RuneInfo::new('ᚒ', "úr", "u", "heather, earth"),
+    RuneInfo::new('ᚓ', "edad", "e", "aspen"),
+    RuneInfo::new('ᚔ', "idad", "i", "yew"),
+    RuneInfo::new('ᚕ', "éabhadh", "ea", "aspen"),
+    RuneInfo::new('ᚖ', "ór", "oi", "spindle tree, gold"),
+    RuneInfo::new('ᚗ', "uilleann", "ui", "honeysuckle, elbow"),
+    RuneInfo::new('ᚘ', "ifín", "ia", "gooseberry, pine"),
+    RuneInfo::new('ᚙ', "eamhancholl", "ae", "twin of hazel"),
+    RuneInfo::new('ᚚ', "peith", "p", "dwarf elder"),
+];
+
+const MYSTIC_INFO: &[RuneInfo] = &[
+    RuneInfo::new('☽', "waxing moon", "", "growth, beginnings"),
+    RuneInfo::new('☾', "waning moon", "", "release, endings"),
+    RuneInfo::new('✧', "four-pointed star", "", "a guiding light"),
+    RuneInfo::new('✦', "black four-pointed star", "", "a fixed star"),
+    RuneInfo::new('◈', "diamond in diamond", "", "crystal"),
+    RuneInfo::new('◇', "white diamond", "", "purity"),
+    RuneInfo::new('⁂', "asterism", "", "a constellation"),
+    RuneInfo::new('⊕', "circled plus", "", "the Earth"),
+    RuneInfo::new('⊗', "circled times", "", "binding"),
+    RuneInfo::new('⊛', "circled asterisk", "", "radiance"),
+    RuneInfo::new(
+        '⌘',
+        "place of interest",
+        "",
+        "a notable place, Saint John's arms",
+    ),
+    RuneInfo::new('⍟', "circled star", "", "a spell circle"),
+    RuneInfo::new('♅', "Uranus", "", "the sky"),
+    RuneInfo::new('♆', "Neptune", "", "the sea"),
+    RuneInfo::new('♇', "Pluto", "", "the underworld"),
+    RuneInfo::new('⚝', "outlined star", "", "the pentacle"),
+    RuneInfo::new('✡', "hexagram", "", "union of opposites"),
+    RuneInfo::new('⬡', "white hexagon", "", "a honeycomb"),
+    RuneInfo::new('⬢', "black hexagon", "", "a cell"),
+    RuneInfo::new('⏣', "benzene ring", "", "essence"),
+    RuneInfo::new('⏥', "flatness", "", "stillness"),
+    RuneInfo::new('◉', "fisheye", "", "a watchful eye"),
+    RuneInfo::new('◎', "bullseye", "", "focus"),
+    RuneInfo::new('⦿', "circled bullet", "", "the sun, gold"),
+];
+
+const OLD_TURKIC_INFO: &[RuneInfo] = &[
+    RuneInfo::new('𐰀', "orkhon a", "a, e", "vowel, mostly left unwritten"),
+    RuneInfo::new('𐰁', "yenisei a", "a, e", "Yenisei vowel"),
+    RuneInfo::new('𐰂', "yenisei ä", "ä", "Yenisei front vowel"),
+    RuneInfo::new('𐰃', "orkhon i", "ı, i", "vowel"),
+    RuneInfo::new('𐰄', "yenisei i", "ı, i", "Yenisei vowel"),
+    RuneInfo::new('𐰅', "yenisei e", "e", "Yenisei front vowel"),
+    RuneInfo::new('𐰆', "orkhon o", "o, u", "back rounded vowel"),
+    RuneInfo::new('𐰇', "orkhon ö", "ö, ü", "front rounded vowel"),
+    RuneInfo::new('𐰈', "yenisei ö", "ö, ü", "Yenisei front rounded vowel"),
+    RuneInfo::new('𐰉', "orkhon ab", "b", "b among back vowels"),
+    RuneInfo::new('𐰊', "yenisei ab", "b", "Yenisei b among back vowels"),
+    RuneInfo::new('𐰋', "orkhon äb", "b", "b among front vowels"),
+    RuneInfo::new('𐰌', "yenisei äb", "b", "Yenisei b among front vowels"),
+    RuneInfo::new('𐰍', "orkhon ag", "ɣ", "g among back vowels"),
+    RuneInfo::new('𐰎', "yenisei ag", "ɣ", "Yenisei g among back vowels"),
+    RuneInfo::new('𐰏', "orkhon äg", "g", "g among front vowels"),
+    RuneInfo::new('𐰐', "yenisei äg", "g", "Yenisei g among front vowels"),
+    RuneInfo::new('𐰑', "orkhon ad", "d", "d among back vowels"),
+    RuneInfo::new('𐰒', "yenisei ad", "d", "Yenisei d among back vowels"),
+    RuneInfo::new('𐰓', "orkhon äd", "d", "d among front vowels"),
+    RuneInfo::new('𐰔', "orkhon ez", "z", "z with any vowel"),
+    RuneInfo::new('𐰕', "yenisei ez", "z", "Yenisei z"),
+    RuneInfo::new('𐰖', "orkhon ay", "j", "y among back vowels"),
+    RuneInfo::new('𐰗', "yenisei ay", "j", "Yenisei y among back vowels"),
+    RuneInfo::new('𐰘', "orkhon äy", "j", "y among front vowels"),
+    RuneInfo::new('𐰙', "yenisei äy", "j", "Yenisei y among front vowels"),
+    RuneInfo::new('𐰚', "orkhon äk", "k", "k among front vowels"),
+    RuneInfo::new('𐰛', "yenisei äk", "k", "Yenisei k among front vowels"),
+    RuneInfo::new('𐰜', "orkhon ök", "øk, yk", "k after ö or ü"),
+    RuneInfo::new('𐰝', "yenisei ök", "øk, yk", "Yenisei k after ö or ü"),
+    RuneInfo::new('𐰞', "orkhon al", "l", "l among back vowels"),
+    RuneInfo::new('𐰟', "yenisei al", "l", "Yenisei l among back vowels"),
+    RuneInfo::new('𐰠', "orkhon äl", "l", "l among front vowels"),
+    RuneInfo::new('𐰡', "orkhon elt", "lt", "the cluster lt"),
+    RuneInfo::new('𐰢', "orkhon em", "m", "m with any vowel"),
+    RuneInfo::new('𐰣', "orkhon an", "n", "n among back vowels"),
+    RuneInfo::new('𐰤', "orkhon än", "n", "n among front vowels"),
+    RuneInfo::new('𐰥', "yenisei än", "n", "Yenisei n among front vowels"),
+    RuneInfo::new('𐰦', "orkhon ent", "nt", "the cluster nt"),
+    RuneInfo::new('𐰧', "yenisei ent", "nt", "Yenisei cluster nt"),
+    RuneInfo::new('𐰨', "orkhon enč", "ntʃ", "the cluster nč"),
+    RuneInfo::new('𐰩', "yenisei enč", "ntʃ", "Yenisei cluster nč"),
+    RuneInfo::new('𐰪', "orkhon eny", "ɲ", "palatal n"),
+    RuneInfo::new('𐰫', "yenisei eny", "ɲ", "Yenisei palatal n"),
+    RuneInfo::new('𐰬', "yenisei ang", "ŋ", "Yenisei ng"),
+    RuneInfo::new('𐰭', "orkhon eng", "ŋ", "ng"),
+    RuneInfo::new('𐰮', "yenisei äng", "ŋ", "Yenisei ng among front vowels"),
+    RuneInfo::new('𐰯', "orkhon ep", "p", "p with any vowel"),
+    RuneInfo::new('𐰰', "orkhon op", "op, up", "p after o or u"),
+    RuneInfo::new('𐰱', "orkhon ič", "itʃ", "č after i"),
+    RuneInfo::new('𐰲', "orkhon eč", "tʃ", "č with any vowel"),
+    RuneInfo::new('𐰳', "yenisei eč", "tʃ", "Yenisei č"),
+    RuneInfo::new('𐰴', "orkhon aq", "q", "q among back vowels"),
+    RuneInfo::new('𐰵', "yenisei aq", "q", "Yenisei q among back vowels"),
+    RuneInfo::new('𐰶', "orkhon ïq", "ɯq, qɯ", "q beside ı"),
+    RuneInfo::new('𐰷', "yenisei ïq", "ɯq, qɯ", "Yenisei q beside ı"),
+    RuneInfo::new('𐰸', "orkhon oq", "oq, uq", "q beside o or u"),
+    RuneInfo::new('𐰹', "yenisei oq", "oq, uq", "Yenisei q beside o or u"),
+    RuneInfo::new('𐰺', "orkhon ar", "r", "r among back vowels"),
+    RuneInfo::new('𐰻', "yenisei ar", "r", "Yenisei r among back vowels"),
+    RuneInfo::new('𐰼', "orkhon är", "r", "r among front vowels"),
+    RuneInfo::new('𐰽', "orkhon as", "s", "s among back vowels"),
+    RuneInfo::new('𐰾', "orkhon äs", "s", "s among front vowels"),
+    RuneInfo::new('𐰿', "orkhon aš", "ʃ", "š among back vowels"),
+    RuneInfo::new('𐱀', "yenisei aš", "ʃ", "Yenisei š among back vowels"),
+    RuneInfo::new('𐱁', "orkhon eš", "ʃ", "š among front vowels"),
+    RuneInfo::new('𐱂', "yenisei eš", "ʃ", "Yenisei š among front vowels"),
+    RuneInfo::new('𐱃', "orkhon at", "t", "t among back vowels"),
+    RuneInfo::new('𐱄', "yenisei at", "t", "Yenisei t among back vowels"),
+    RuneInfo::new('𐱅', "orkhon ät", "t", "t among front vowels"),
+    RuneInfo::new('𐱆', "yenisei ät", "t", "Yenisei t among front vowels"),
+    RuneInfo::new('𐱇', "orkhon ot", "ot, ut", "t beside o or u"),
+    RuneInfo::new('𐱈', "orkhon baš", "baʃ", "word sign: head, chief"),
+];
+
+const OLD_ITALIC_INFO: &[RuneInfo] = &[
+    RuneInfo::new('𐌀', "a", "a", "from Greek alpha"),
+    RuneInfo::new('𐌁', "be", "b", "from Greek beta"),
+    RuneInfo::new('𐌂', "ke", "k", "from Greek gamma"),
+    RuneInfo::new('𐌃', "de", "d", "from Greek delta"),
+    RuneInfo::new('𐌄', "e", "e", "from Greek epsilon"),
+    RuneInfo::new('𐌅', "ve", "w", "from Greek digamma"),
+    RuneInfo::new('𐌆', "ze", "ts", "from Greek zeta"),
+    RuneInfo::new('𐌇', "he", "h", "from Greek heta"),
+    RuneInfo::new('𐌈', "the", "tʰ", "from Greek theta"),
+    RuneInfo::new('𐌉', "i", "i", "from Greek iota"),
+    RuneInfo::new('𐌊', "ka", "k", "from Greek kappa"),
+    RuneInfo::new('𐌋', "el", "l", "from Greek lambda"),
+    RuneInfo::new('𐌌', "em", "m", "from Greek mu"),
+    RuneInfo::new('𐌍', "en", "n", "from Greek nu"),
+    RuneInfo::new('𐌎', "esh", "ʃ", "from Greek xi"),
+    RuneInfo::new('𐌏', "o", "o", "from Greek omicron"),
+    RuneInfo::new('𐌐', "pe", "p", "from Greek pi"),
+    RuneInfo::new('𐌑', "she", "ʃ", "from Greek san"),
+    RuneInfo::new('𐌒', "ku", "k", "from Greek qoppa"),
+    RuneInfo::new('𐌓', "er", "r", "from Greek rho"),
+    RuneInfo::new('𐌔', "es", "s", "from Greek sigma"),
+    RuneInfo::new('𐌕', "te", "t", "from Greek tau"),
+    RuneInfo::new('𐌖', "u", "u", "from Greek upsilon"),
+    RuneInfo::new('𐌗', "eks", "ks", "from western Greek chi"),
+    RuneInfo::new('𐌘', "phe", "pʰ", "from Greek phi"),
+    RuneInfo::new('𐌙', "khe", "kʰ", "from Greek psi"),
+    RuneInfo::new('𐌚', "ef", "f", "the Etruscan figure-eight f"),
+    RuneInfo::new('𐌛', "ers", "ř", "an Umbrian addition"),
+    RuneInfo::new('𐌜', "che", "tʃ", "an Umbrian addition"),
+    RuneInfo::new('𐌝', "ii", "í", "an Oscan addition"),
+    RuneInfo::new('𐌞', "uu", "ú", "an Oscan addition"),
+    RuneInfo::new('𐌟', "ess", "s", "a late Etruscan s"),
+];
+
+const GOTHIC_INFO: &[RuneInfo] = &[
+    RuneInfo::new('𐌰', "ahsa", "a", "ear of grain"),
+    RuneInfo::new('𐌱', "bairkan", "b", "birch"),
+    RuneInfo::new('𐌲', "giba", "g", "gift"),
+    RuneInfo::new('𐌳', "dags", "d", "day"),
+    RuneInfo::new('𐌴', "aíƕus", "eː", "horse"),
+    RuneInfo::new('𐌵', "qaírþra", "kʷ", "unknown"),
+    RuneInfo::new('𐌶', "iuja", "z", "unknown"),
+    RuneInfo::new('𐌷', "hagl", "h", "hail"),
+    RuneInfo::new('𐌸', "þiuþ", "θ", "the good"),
+    RuneInfo::new('𐌹', "eis", "i", "ice"),
+    RuneInfo::new('𐌺', "kusma", "k", "unknown, perhaps a boil"),
+    RuneInfo::new('𐌻', "lagus", "l", "lake, sea"),
+    RuneInfo::new('𐌼', "manna", "m", "man"),
+    RuneInfo::new('𐌽', "nauþs", "n", "need"),
+    RuneInfo::new('𐌾', "jēr", "j", "year"),
+    RuneInfo::new('𐌿', "ūrus", "u", "aurochs"),
+    RuneInfo::new('𐍀', "paírþra", "p", "unknown"),
+    RuneInfo::new('𐍁', "ninety", "", "the numeral 90"),
+    RuneInfo::new('𐍂', "raida", "r", "ride, chariot"),
+    RuneInfo::new('𐍃', "sauil", "s", "sun"),
+    RuneInfo::new('𐍄', "teiws", "t", "the god Tiw"),
+    RuneInfo::new('𐍅', "winja", "w", "pasture"),
+    RuneInfo::new('𐍆', "faíhu", "f", "cattle, wealth"),
+    RuneInfo::new('𐍇', "iggws", "kʰ", "the god Ing"),
+    RuneInfo::new('𐍈', "ƕair", "hʷ", "cauldron"),
+    RuneInfo::new('𐍉', "ōþal", "oː", "heritage, estate"),
+    RuneInfo::new('𐍊', "nine hundred", "", "the numeral 900"),
+];
+
+const OLD_HUNGARIAN_INFO: &[RuneInfo] = &[
+    RuneInfo::new('𐲀', "a", "ɒ", "open back vowel"),
+    RuneInfo::new('𐲁', "á", "aː", "long vowel"),
+    RuneInfo::new('𐲂', "eb", "b", "consonant"),
+    RuneInfo::new('𐲃', "amb", "mb", "ligature"),
+    RuneInfo::new('𐲄', "ec", "ts", "consonant"),
+    RuneInfo::new('𐲅', "enc", "nts", "ligature"),
+    RuneInfo::new('𐲆', "ecs", "tʃ", "consonant"),
+    RuneInfo::new('𐲇', "ed", "d", "consonant"),
+    RuneInfo::new('𐲈', "and", "nd", "ligature"),
+    RuneInfo::new('𐲉', "e", "ɛ", "open front vowel"),
+    RuneInfo::new('𐲊', "close e", "e", "close front vowel"),
+    RuneInfo::new('𐲋', "é", "eː", "long vowel"),
+    RuneInfo::new('𐲌', "ef", "f", "consonant"),
+    RuneInfo::new('𐲍', "eg", "g", "consonant"),
+    RuneInfo::new('𐲎', "egy", "ɟ", "palatal consonant"),
+    RuneInfo::new('𐲏', "eh", "h", "consonant"),
+    RuneInfo::new('𐲐', "i", "i", "vowel"),
+    RuneInfo::new('𐲑', "í", "iː", "long vowel"),
+    RuneInfo::new('𐲒', "ej", "j", "consonant"),
+    RuneInfo::new('𐲓', "ek", "k", "k among front vowels"),
+    RuneInfo::new('𐲔', "ak", "k", "k among back vowels"),
+    RuneInfo::new('𐲕', "unk", "ŋk", "ligature"),
+    RuneInfo::new('𐲖', "el", "l", "consonant"),
+    RuneInfo::new('𐲗', "ely", "j", "palatal consonant"),
+    RuneInfo::new('𐲘', "em", "m", "consonant"),
+    RuneInfo::new('𐲙', "en", "n", "consonant"),
+    RuneInfo::new('𐲚', "eny", "ɲ", "palatal consonant"),
+    RuneInfo::new('𐲛', "o", "o", "vowel"),
+    RuneInfo::new('𐲜', "ó", "oː", "long vowel"),
+    RuneInfo::new(
+        '𐲝',
+        "Nikolsburg ö",
+        "ø",
+        "vowel, as in the Nikolsburg alphabet",
+    ),
+    RuneInfo::new('𐲞', "Rudimenta ö", "ø", "vowel, as in the Rudimenta"),
+    RuneInfo::new('𐲟', "ő", "øː", "long vowel"),
+    RuneInfo::new('𐲠', "ep", "p", "consonant"),
+    RuneInfo::new('𐲡', "emp", "mp", "ligature"),
+    RuneInfo::new('𐲢', "er", "r", "consonant"),
+    RuneInfo::new('𐲣', "short er", "r", "consonant, short form"),
+    RuneInfo::new('𐲤', "es", "ʃ", "consonant"),
+    RuneInfo::new('𐲥', "esz", "s", "consonant"),
+    RuneInfo::new('𐲦', "et", "t", "consonant"),
+    RuneInfo::new('𐲧', "ent", "nt", "ligature"),
+    RuneInfo::new('𐲨', "ety", "c", "palatal consonant"),
+    RuneInfo::new('𐲩', "ech", "x", "consonant"),
+    RuneInfo::new('𐲪', "u", "u", "vowel"),
+    RuneInfo::new('𐲫', "ú", "uː", "long vowel"),
+    RuneInfo::new(
+        '𐲬',
+        "Nikolsburg ü",
+        "y",
+        "vowel, as in the Nikolsburg alphabet",
+    ),
+    RuneInfo::new('𐲭', "Rudimenta ü", "y", "vowel, as in the Rudimenta"),
+    RuneInfo::new('𐲮', "ev", "v", "consonant"),
+    RuneInfo::new('𐲯', "ez", "z", "consonant"),
+    RuneInfo::new('𐲰', "ezs", "ʒ", "consonant"),
+    RuneInfo::new('𐲱', "ent-shaped sign", "", "a sign shaped like ent"),
+    RuneInfo::new('𐲲', "us", "us", "ligature"),
+];
+
+const TIFINAGH_INFO: &[RuneInfo] = &[
+    RuneInfo::new('ⴰ', "ya", "a", "vowel"),
+    RuneInfo::new('ⴱ', "yab", "b", "consonant"),
+    RuneInfo::new('ⴲ', "yabh", "β", "Neo-Tifinagh consonant"),
+    RuneInfo::new('ⴳ', "yag", "g", "consonant"),
+    RuneInfo::new('ⴴ', "yaghh", "ɣ", "Neo-Tifinagh consonant"),
+    RuneInfo::new('ⴵ', "yaj", "dʒ", "Berber Academy form"),
+    RuneInfo::new('ⴶ', "yaj", "dʒ", "consonant"),
+    RuneInfo::new('ⴷ', "yad", "d", "consonant"),
+    RuneInfo::new('ⴸ', "yadh", "ð", "Neo-Tifinagh consonant"),
+    RuneInfo::new('ⴹ', "yadd", "dˤ", "emphatic consonant"),
+    RuneInfo::new('ⴺ', "yaddh", "ðˤ", "Neo-Tifinagh emphatic"),
+    RuneInfo::new('ⴻ', "yey", "ə", "vowel"),
+    RuneInfo::new('ⴼ', "yaf", "f", "consonant"),
+    RuneInfo::new('ⴽ', "yak", "k", "consonant"),
+    RuneInfo::new('ⴾ', "yak", "k", "Tuareg form"),
+    RuneInfo::new('ⴿ', "yakhh", "x", "Neo-Tifinagh consonant"),
+    RuneInfo::new('ⵀ', "yah", "h", "consonant"),
+    RuneInfo::new('ⵁ', "yah", "h", "Berber Academy form"),
+    RuneInfo::new('ⵂ', "yah", "h", "Tuareg form"),
+    RuneInfo::new('ⵃ', "yahh", "ħ", "consonant"),
+    RuneInfo::new('ⵄ', "yaa", "ʕ", "consonant"),
+    RuneInfo::new('ⵅ', "yakh", "χ", "consonant"),
+    RuneInfo::new('ⵆ', "yakh", "χ", "Tuareg form"),
+    RuneInfo::new('ⵇ', "yaq", "q", "consonant"),
+    RuneInfo::new('ⵈ', "yaq", "q", "Tuareg form"),
+    RuneInfo::new('ⵉ', "yi", "i", "vowel"),
+    RuneInfo::new('ⵊ', "yazh", "ʒ", "consonant"),
+    RuneInfo::new('ⵋ', "yazh", "ʒ", "Ahaggar form"),
+    RuneInfo::new('ⵌ', "yazh", "ʒ", "Tuareg form"),
+    RuneInfo::new('ⵍ', "yal", "l", "consonant"),
+    RuneInfo::new('ⵎ', "yam", "m", "consonant"),
+    RuneInfo::new('ⵏ', "yan", "n", "consonant"),
+    RuneInfo::new('ⵐ', "yagn", "ɲ", "Tuareg consonant"),
+    RuneInfo::new('ⵑ', "yang", "ŋ", "Tuareg consonant"),
+    RuneInfo::new('ⵒ', "yap", "p", "consonant"),
+    RuneInfo::new('ⵓ', "yu", "u", "vowel"),
+    RuneInfo::new('ⵔ', "yar", "r", "consonant"),
+    RuneInfo::new('ⵕ', "yarr", "rˤ", "emphatic consonant"),
+    RuneInfo::new('ⵖ', "yagh", "ɣ", "consonant"),
+    RuneInfo::new('ⵗ', "yagh", "ɣ", "Tuareg form"),
+    RuneInfo::new('ⵘ', "yagh", "ɣ", "Aïr form"),
+    RuneInfo::new('ⵙ', "yas", "s", "consonant"),
+    RuneInfo::new('ⵚ', "yass", "sˤ", "emphatic consonant"),
+    RuneInfo::new('ⵛ', "yash", "ʃ", "consonant"),
+    RuneInfo::new('ⵜ', "yat", "t", "consonant"),
+    RuneInfo::new('ⵝ', "yath", "θ", "Neo-Tifinagh consonant"),
+    RuneInfo::new('ⵞ', "yach", "tʃ", "consonant"),
+    RuneInfo::new('ⵟ', "yatt", "tˤ", "emphatic consonant"),
+    RuneInfo::new('ⵠ', "yav", "v", "Neo-Tifinagh consonant"),
+    RuneInfo::new('ⵡ', "yaw", "w", "consonant"),
+    RuneInfo::new('ⵢ', "yay", "j", "consonant"),
+    RuneInfo::new('ⵣ', "yaz", "z", "emblem of Amazigh identity"),
+    RuneInfo::new('ⵤ', "yaz", "z", "Tawellemet form"),
+    RuneInfo::new('ⵥ', "yazz", "zˤ", "emphatic consonant"),
+    RuneInfo::new('ⵦ', "ye", "e", "Tuareg vowel"),
+    RuneInfo::new('ⵧ', "yo", "o", "Tuareg vowel"),
+];
+
+/// Name, sound and meaning of `ch` as a glyph of `set`. Known for every
+/// built-in set; mixed and custom sets use whichever table the glyph comes
+/// from first.
+pub fn rune_info(set: &RuneSet, ch: char) -> Option<RuneInfo> {
+    let tables: &[&[RuneInfo]] = match set {
+        RuneSet::Elder => &[ELDER_INFO],
+        RuneSet::Younger => &[YOUNGER_INFO],
+        RuneSet::Anglo => &[ANGLO_INFO],
+        RuneSet::Medieval => &[MEDIEVAL_INFO],
+        RuneSet::Ogham => &[OGHAM_INFO],
+        RuneSet::Mystic => &[MYSTIC_INFO],
+        RuneSet::All => &[
+            ELDER_INFO,
+            YOUNGER_INFO,
+            ANGLO_INFO,
+            OGHAM_INFO,
+            MYSTIC_INFO,
+        ],
+        RuneSet::Custom(_) => &[
+            ELDER_INFO,
+            YOUNGER_INFO,
+            ANGLO_INFO,
+            MEDIEVAL_INFO,
+            OGHAM_INFO,
+            MYSTIC_INFO,
+            OLD_TURKIC_INFO,
+            OLD_ITALIC_INFO,
+            GOTHIC_INFO,
+            OLD_HUNGARIAN_INFO,
+            TIFINAGH_INFO,
+        ],
+        RuneSet::OldTurkic => &[OLD_TURKIC_INFO],
+        RuneSet::OldItalic => &[OLD_ITALIC_INFO],
+        RuneSet::Gothic => &[GOTHIC_INFO],
+        RuneSet::OldHungarian => &[OLD_HUNGARIAN_INFO],
+        RuneSet::Tifinagh => &[TIFINAGH_INFO],
+    };
+    tables
+        .iter()
+        .flat_map(|table| table.iter())
+        .find(|info| info.glyph == ch)
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_glyph_of_the_builtin_sets_has_a_name() {
+        for set in RuneSet::BUILTIN {
+            for ch in set.glyphs() {
+                assert!(rune_info(&set, ch).is_some(), "{ch} in {}", set.name());
+            }
+        }
+        assert_eq!(rune_info(&RuneSet::Gothic, '𐌰').unwrap().name, "ahsa");
+        assert_eq!(rune_info(&RuneSet::Tifinagh, 'ᚠ'), None);
+    }
+
+    #[test]
+    fn shared_runes_are_named_per_set() {
+        assert_eq!(rune_info(&RuneSet::Elder, 'ᚠ').unwrap().name, "fehu");
+        assert_eq!(rune_info(&RuneSet::Younger, 'ᚠ').unwrap().name, "fé");
+        assert_eq!(rune_info(&RuneSet::Anglo, 'ᚠ').unwrap().name, "feoh");
+        assert_eq!(rune_info(&RuneSet::All, 'ᚠ').unwrap().name, "fehu");
+    }
+
+    #[test]
+    fn sets_list_every_builtin_containing_the_glyph() {
+        let fehu = rune_info(&RuneSet::Elder, 'ᚠ').unwrap();
+        assert_eq!(
+            fehu.sets(),
+            [
+                RuneSet::Elder,
+                RuneSet::Younger,
+                RuneSet::Anglo,
+                RuneSet::Medieval
+            ]
+        );
+        let dagaz = rune_info(&RuneSet::Elder, 'ᛞ').unwrap();
+        assert_eq!(dagaz.sets(), [RuneSet::Elder]);
+    }
+}
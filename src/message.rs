@@ -0,0 +1,136 @@
+use crate::runes::RuneSet;
+use crate::translit::transliterate;
+use crate::width::glyph_width;
+use rand::Rng;
+
+// ── Message reveal ────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Phase {
+    Forming,
+    Holding,
+    Dissolving,
+    Resting,
+}
+
+/// One glyph of the phrase at its place on screen.
+struct Glyph {
+    x: u16,
+    y: u16,
+    ch: char,
+    locked: bool,
+}
+
+/// A phrase that rain streams lock into place in the middle of the screen,
+/// holds for a while and then lets dissolve back into rain, over and over.
+pub(crate) struct Message {
+    text: String,
+    glyphs: Vec<Glyph>,
+    phase: Phase,
+    timer: u64, // ticks spent in the current phase
+}
+
+impl Message {
+    pub(crate) fn new(text: &str) -> Self {
+        Message {
+            text: text.to_string(),
+            glyphs: Vec::new(),
+            phase: Phase::Forming,
+            timer: 0,
+        }
+    }
+
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Transliterate the phrase into `rune_set` and center it on a
+    /// `cols × rows` screen, one glyph every `pitch` cells, wrapping between
+    /// words. Starts forming anew.
+    pub(crate) fn layout(
+        &mut self,
+        cols: u16,
+        rows: u16,
+        pitch: u16,
+        rune_set: &RuneSet,
+        ambiguous_width: u8,
+    ) {
+        let runes = transliterate(&self.text, rune_set);
+        let max_len = (cols / pitch).saturating_sub(2).max(1) as usize;
+
+        let mut lines: Vec<Vec<char>> = Vec::new();
+        for word in runes.split_whitespace() {
+            let word: Vec<char> = word.chars().collect();
+            match lines.last_mut() {
+                Some(line) if line.len() + 1 + word.len() <= max_len => {
+                    line.push(' ');
+                    line.extend(word);
+                }
+                _ => lines.extend(word.chunks(max_len).map(|chunk| chunk.to_vec())),
+            }
+        }
+
+        self.glyphs.clear();
+        let top = (rows / 2).saturating_sub(lines.len() as u16 / 2);
+        for (row, line) in lines.iter().enumerate() {
+            let y = top + row as u16;
+            let left = (cols / pitch).saturating_sub(line.len() as u16) / 2 * pitch;
+            for (i, &ch) in line.iter().enumerate() {
+                let x = left + i as u16 * pitch;
+                if ch != ' ' && y < rows && x + glyph_width(ch, ambiguous_width) <= cols {
+                    self.glyphs.push(Glyph {
+                        x,
+                        y,
+                        ch,
+                        locked: false,
+                    });
+                }
+            }
+        }
+        self.phase = Phase::Forming;
+        self.timer = 0;
+    }
+
+    /// Advance one frame: glyphs lock as a stream head passes over them, and
+    /// stragglers lock on their own once forming takes longer than a few
+    /// seconds.
+    pub(crate) fn tick(&mut self, heads: &[(u16, u16)], fps: u64, rng: &mut impl Rng) {
+        self.timer += 1;
+        match self.phase {
+            Phase::Forming => {
+                let overdue = self.timer > fps * 4;
+                for glyph in self.glyphs.iter_mut().filter(|glyph| !glyph.locked) {
+                    glyph.locked =
+                        heads.contains(&(glyph.x, glyph.y)) || (overdue && rng.gen_ratio(1, 8));
+                }
+                if self.glyphs.iter().all(|glyph| glyph.locked) {
+                    self.enter(Phase::Holding);
+                }
+            }
+            Phase::Holding if self.timer > fps * 5 => self.enter(Phase::Dissolving),
+            Phase::Dissolving => {
+                for glyph in self.glyphs.iter_mut().filter(|glyph| glyph.locked) {
+                    glyph.locked = !rng.gen_ratio(1, 12);
+                }
+                if self.glyphs.iter().all(|glyph| !glyph.locked) {
+                    self.enter(Phase::Resting);
+                }
+            }
+            Phase::Resting if self.timer > fps * 8 => self.enter(Phase::Forming),
+            _ => {}
+        }
+    }
+
+    fn enter(&mut self, phase: Phase) {
+        self.phase = phase;
+        self.timer = 0;
+    }
+
+    /// Locked glyphs as `((x, y), glyph)`.
+    pub(crate) fn locked(&self) -> impl Iterator<Item = ((u16, u16), char)> + '_ {
+        self.glyphs
+            .iter()
+            .filter(|glyph| glyph.locked)
+            .map(|glyph| ((glyph.x, glyph.y), glyph.ch))
+    }
+}
@@ -0,0 +1,188 @@
+// ── OKLab / OKLCH ─────────────────────────────────────────────────────
+//
+// Björn Ottosson's perceptual color space: equal steps in L look equally
+// bright whatever the hue, so gradients blended here keep even midtones
+// instead of the muddy dip of raw sRGB blending.
+// See <https://bottosson.github.io/posts/oklab/>.
+
+/// A color in OKLab: lightness `l` from 0.0 (black) to 1.0 (white), and the
+/// green–red (`a`) and blue–yellow (`b`) axes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// OKLab in polar form: lightness, chroma and hue in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+fn to_linear(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn from_linear(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+impl Oklab {
+    pub fn from_rgb((r, g, b): (u8, u8, u8)) -> Self {
+        let (r, g, b) = (to_linear(r), to_linear(g), to_linear(b));
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        Oklab {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+
+    /// Linear sRGB channels, which fall outside 0.0-1.0 for colors the
+    /// screen can't show.
+    fn to_linear_rgb(self) -> (f32, f32, f32) {
+        let l = (self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b).powi(3);
+        let m = (self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b).powi(3);
+        let s = (self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b).powi(3);
+        (
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        )
+    }
+
+    /// The nearest sRGB color, clipping channels that fall out of range.
+    pub fn to_rgb(self) -> (u8, u8, u8) {
+        let (r, g, b) = self.to_linear_rgb();
+        let channel = |c: f32| (from_linear(c.clamp(0.0, 1.0)) * 255.0).round() as u8;
+        (channel(r), channel(g), channel(b))
+    }
+
+    fn in_gamut(self) -> bool {
+        let (r, g, b) = self.to_linear_rgb();
+        [r, g, b].iter().all(|c| (-1e-4..=1.0 + 1e-4).contains(c))
+    }
+
+    /// Blend towards `other`: `t` 0.0 gives `self`, 1.0 gives `other`.
+    pub fn lerp(self, other: Oklab, t: f32) -> Oklab {
+        Oklab {
+            l: self.l + (other.l - self.l) * t,
+            a: self.a + (other.a - self.a) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+
+    pub fn to_lch(self) -> Oklch {
+        Oklch {
+            l: self.l,
+            c: self.a.hypot(self.b),
+            h: self.b.atan2(self.a).to_degrees().rem_euclid(360.0),
+        }
+    }
+}
+
+impl Oklch {
+    pub fn to_lab(self) -> Oklab {
+        let (sin, cos) = self.h.to_radians().sin_cos();
+        Oklab {
+            l: self.l,
+            a: self.c * cos,
+            b: self.c * sin,
+        }
+    }
+
+    /// The sRGB color of this lightness and hue, with chroma reduced as far
+    /// as needed to fit the screen's gamut rather than clipping channels,
+    /// which would shift the hue.
+    pub fn to_rgb(self) -> (u8, u8, u8) {
+        if self.to_lab().in_gamut() {
+            return self.to_lab().to_rgb();
+        }
+        let (mut low, mut high) = (0.0, self.c);
+        for _ in 0..16 {
+            let c = (low + high) / 2.0;
+            let fits = Oklch { c, ..self }.to_lab().in_gamut();
+            if fits {
+                low = c;
+            } else {
+                high = c;
+            }
+        }
+        Oklch { c: low, ..self }.to_lab().to_rgb()
+    }
+}
+
+/// Blend two sRGB colors through OKLab: `t` 0.0 gives `from`, 1.0 gives `to`.
+pub fn mix(from: (u8, u8, u8), to: (u8, u8, u8), t: f32) -> (u8, u8, u8) {
+    if t <= 0.0 {
+        return from;
+    }
+    if t >= 1.0 {
+        return to;
+    }
+    Oklab::from_rgb(from).lerp(Oklab::from_rgb(to), t).to_rgb()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn srgb_round_trips() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(17) {
+                for b in (0..=255).step_by(51) {
+                    assert_eq!(Oklab::from_rgb((r, g, b)).to_rgb(), (r, g, b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_reference_values() {
+        let white = Oklab::from_rgb((255, 255, 255));
+        assert!((white.l - 1.0).abs() < 1e-3 && white.a.abs() < 1e-3 && white.b.abs() < 1e-3);
+        let red = Oklab::from_rgb((255, 0, 0));
+        assert!((red.l - 0.628).abs() < 1e-3, "{red:?}");
+        assert!((red.a - 0.225).abs() < 1e-3, "{red:?}");
+        assert!((red.b - 0.126).abs() < 1e-3, "{red:?}");
+        assert!((red.to_lch().h - 29.2).abs() < 0.1, "{red:?}");
+    }
+
+    #[test]
+    fn midpoints_are_perceptually_even() {
+        // Halfway from black to white in lightness, not in raw channel values
+        let (gray, _, _) = mix((0, 0, 0), (255, 255, 255), 0.5);
+        let l = Oklab::from_rgb((gray, gray, gray)).l;
+        assert!((l - 0.5).abs() < 0.005, "{gray} has lightness {l}");
+        assert_eq!(mix((10, 20, 30), (200, 100, 0), 0.0), (10, 20, 30));
+        assert_eq!(mix((10, 20, 30), (200, 100, 0), 1.0), (200, 100, 0));
+    }
+
+    #[test]
+    fn out_of_gamut_chroma_keeps_its_hue() {
+        let lch = Oklch {
+            l: 0.7,
+            c: 0.4,
+            h: 140.0,
+        };
+        let rgb = lch.to_rgb();
+        let back = Oklab::from_rgb(rgb).to_lch();
+        assert!((back.h - 140.0).abs() < 2.0, "{back:?}");
+        assert!((back.l - 0.7).abs() < 0.01, "{back:?}");
+    }
+}
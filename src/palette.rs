@@ -0,0 +1,393 @@
+use crate::oklab::{mix, Oklch};
+use crossterm::style::Color;
+use std::sync::Arc;
+
+// ── Color palettes ────────────────────────────────────────────────────
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Palette {
+    Arcane,
+    Emerald,
+    Frost,
+    Ember,
+    Rainbow,
+    BlinkingRainbow,
+    Custom(Arc<CustomPalette>),
+}
+
+/// A user-defined palette: a head color and a gradient of color stops along
+/// the trail, at positions from 0.0 (tip of the tail) to 1.0 (just behind the
+/// head), optionally over a background of its own.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomPalette {
+    name: String,
+    head: (u8, u8, u8),
+    stops: Vec<(f32, (u8, u8, u8))>, // sorted by position
+    background: Option<Background>,
+}
+
+impl CustomPalette {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn head(&self) -> (u8, u8, u8) {
+        self.head
+    }
+
+    /// Gradient stops as `(position, color)`, by position.
+    pub fn stops(&self) -> &[(f32, (u8, u8, u8))] {
+        &self.stops
+    }
+
+    pub fn background(&self) -> Option<Background> {
+        self.background
+    }
+}
+
+/// Fill behind the rain: one color, or a vertical gradient from the top row
+/// to the bottom one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Background {
+    Solid((u8, u8, u8)),
+    Vertical((u8, u8, u8), (u8, u8, u8)),
+}
+
+impl Background {
+    /// A solid fill from one color, a vertical gradient from two; `None`
+    /// for any other number of colors or colors that aren't RGB.
+    pub fn from_colors(colors: &[Color]) -> Option<Self> {
+        let rgb = |color: &Color| match *color {
+            Color::Rgb { r, g, b } => Some((r, g, b)),
+            _ => None,
+        };
+        match colors {
+            [color] => Some(Background::Solid(rgb(color)?)),
+            [top, bottom] => Some(Background::Vertical(rgb(top)?, rgb(bottom)?)),
+            _ => None,
+        }
+    }
+
+    /// Colors as written in the config file: the solid one, or top and bottom.
+    pub fn colors(&self) -> Vec<(u8, u8, u8)> {
+        match *self {
+            Background::Solid(color) => vec![color],
+            Background::Vertical(top, bottom) => vec![top, bottom],
+        }
+    }
+
+    /// Color of row `y` of a screen `rows` tall.
+    pub fn at(&self, y: u16, rows: u16) -> (u8, u8, u8) {
+        match *self {
+            Background::Solid(color) => color,
+            Background::Vertical(top, bottom) => {
+                let t = y as f32 / rows.saturating_sub(1).max(1) as f32;
+                mix(top, bottom, t)
+            }
+        }
+    }
+}
+
+/// Trail gradients of the built-in palettes, from tail to head.
+const ARCANE: &[(f32, (u8, u8, u8))] = &[(0.0, (40, 10, 80)), (1.0, (180, 60, 255))];
+const EMERALD: &[(f32, (u8, u8, u8))] = &[(0.0, (0, 30, 10)), (1.0, (50, 255, 80))];
+const FROST: &[(f32, (u8, u8, u8))] = &[(0.0, (0, 40, 60)), (1.0, (100, 200, 255))];
+const EMBER: &[(f32, (u8, u8, u8))] = &[
+    (0.0, (60, 0, 0)),
+    (0.5, (158, 30, 15)), // reds linger before the warm glow near the head
+    (1.0, (255, 120, 30)),
+];
+
+/// Color of a gradient at `intensity`, blending the stops around it in
+/// OKLab; outside the first and last stop the gradient holds their color.
+fn gradient(stops: &[(f32, (u8, u8, u8))], intensity: f32) -> Color {
+    let after = stops.partition_point(|&(pos, _)| pos <= intensity);
+    let (r, g, b) = match after {
+        0 => stops[0].1,
+        _ if after == stops.len() => stops[after - 1].1,
+        _ => {
+            let ((pos1, from), (pos2, to)) = (stops[after - 1], stops[after]);
+            mix(from, to, (intensity - pos1) / (pos2 - pos1))
+        }
+    };
+    Color::Rgb { r, g, b }
+}
+
+/// Color of hue `hue` at OKLCH lightness `l` and chroma `c`.
+fn lch(l: f32, c: f32, hue: f32) -> Color {
+    let (r, g, b) = Oklch { l, c, h: hue }.to_rgb();
+    Color::Rgb { r, g, b }
+}
+
+impl Palette {
+    /// Built-in palettes in hotkey order.
+    pub const BUILTIN: [Palette; 6] = [
+        Palette::Arcane,
+        Palette::Emerald,
+        Palette::Frost,
+        Palette::Ember,
+        Palette::Rainbow,
+        Palette::BlinkingRainbow,
+    ];
+
+    pub fn name(&self) -> &str {
+        match self {
+            Palette::Arcane => "Arcane",
+            Palette::Emerald => "Emerald",
+            Palette::Frost => "Frost",
+            Palette::Ember => "Ember",
+            Palette::Rainbow => "Rainbow",
+            Palette::BlinkingRainbow => "Blink",
+            Palette::Custom(custom) => custom.name(),
+        }
+    }
+
+    /// Look up a built-in palette by name or alias, e.g. `frost` or `fire`.
+    pub fn from_name(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "arcane" | "purple" => Some(Palette::Arcane),
+            "emerald" | "green" => Some(Palette::Emerald),
+            "frost" | "blue" | "cyan" => Some(Palette::Frost),
+            "ember" | "red" | "fire" => Some(Palette::Ember),
+            "rainbow" | "multi" => Some(Palette::Rainbow),
+            "blinking" | "blink" | "cmatrix" => Some(Palette::BlinkingRainbow),
+            _ => None,
+        }
+    }
+
+    /// A custom palette from a head color and at least one trail color,
+    /// spread evenly from just behind the head to the tip of the tail;
+    /// `None` unless all of them are RGB.
+    pub fn custom(name: &str, head: Color, trail: &[Color]) -> Option<Self> {
+        let last = trail.len().saturating_sub(1).max(1) as f32;
+        let stops: Vec<(f32, Color)> = trail
+            .iter()
+            .enumerate()
+            .map(|(idx, &color)| (1.0 - idx as f32 / last, color))
+            .collect();
+        Palette::gradient(name, head, &stops)
+    }
+
+    /// A custom palette from a head color and gradient stops `(position,
+    /// color)`, positions running from 0.0 at the tip of the tail to 1.0 just
+    /// behind the head. `None` without stops, with positions outside 0.0-1.0
+    /// or with colors that aren't RGB.
+    pub fn gradient(name: &str, head: Color, stops: &[(f32, Color)]) -> Option<Self> {
+        let rgb = |color: &Color| match *color {
+            Color::Rgb { r, g, b } => Some((r, g, b)),
+            _ => None,
+        };
+        let mut stops = stops
+            .iter()
+            .map(|(pos, color)| Some((*pos, rgb(color)?)))
+            .collect::<Option<Vec<_>>>()?;
+        if stops.is_empty() || stops.iter().any(|(pos, _)| !(0.0..=1.0).contains(pos)) {
+            return None;
+        }
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Some(Palette::Custom(Arc::new(CustomPalette {
+            name: name.to_string(),
+            head: rgb(&head)?,
+            stops,
+            background: None,
+        })))
+    }
+
+    /// This palette painted over `background`. Built-in palettes have no
+    /// background of their own and are returned as they are.
+    pub fn with_background(self, background: Background) -> Self {
+        match self {
+            Palette::Custom(custom) => {
+                let mut custom = Arc::unwrap_or_clone(custom);
+                custom.background = Some(background);
+                Palette::Custom(Arc::new(custom))
+            }
+            builtin => builtin,
+        }
+    }
+
+    /// The palette's own background; `None` leaves the terminal's.
+    pub fn background(&self) -> Option<Background> {
+        match self {
+            Palette::Custom(custom) => custom.background,
+            _ => None,
+        }
+    }
+
+    /// Return a color for a trail cell. `intensity` goes from 1.0 (head) to 0.0 (tail).
+    /// `column_seed` is used for rainbow hue offset.
+    pub fn color(
+        &self,
+        intensity: f32,
+        column_seed: u8,
+        global_tick: u64,
+        coordinate: i32,
+    ) -> Color {
+        let i = intensity.clamp(0.0, 1.0);
+        match self {
+            Palette::Arcane => gradient(ARCANE, i),
+            Palette::Emerald => gradient(EMERALD, i),
+            Palette::Frost => gradient(FROST, i),
+            Palette::Ember => gradient(EMBER, i),
+            Palette::Rainbow => {
+                // Rotate hue based on column_seed + intensity, at even
+                // perceived brightness across hues
+                let hue = ((column_seed as f32 / 255.0) * 360.0 + intensity * 60.0) % 360.0;
+                lch(0.35 + 0.45 * i, 0.15, hue)
+            }
+            Palette::BlinkingRainbow => {
+                // Highly saturated random hue based on coordinate and time for extreme blinking
+                let pseudo = (global_tick
+                    .wrapping_add(coordinate as u64)
+                    .wrapping_add(column_seed as u64))
+                .wrapping_mul(1103515245);
+                let hue = (pseudo % 360) as f32;
+                lch(0.55 + 0.2 * i, 0.25, hue)
+            }
+            Palette::Custom(custom) => gradient(&custom.stops, i),
+        }
+    }
+
+    /// Color of the glowing head cell of a stream.
+    pub fn head_color(&self, column_seed: u8, global_tick: u64, coordinate: i32) -> Color {
+        match self {
+            Palette::Arcane => Color::Rgb {
+                r: 230,
+                g: 180,
+                b: 255,
+            },
+            Palette::Emerald => Color::Rgb {
+                r: 180,
+                g: 255,
+                b: 200,
+            },
+            Palette::Frost => Color::Rgb {
+                r: 200,
+                g: 240,
+                b: 255,
+            },
+            Palette::Ember => Color::Rgb {
+                r: 255,
+                g: 220,
+                b: 150,
+            },
+            Palette::Rainbow => Color::Rgb {
+                r: 255,
+                g: 255,
+                b: 255,
+            },
+            Palette::BlinkingRainbow => {
+                let pseudo = (global_tick
+                    .wrapping_add(coordinate as u64)
+                    .wrapping_add(column_seed as u64))
+                .wrapping_mul(1103515245);
+                let hue = (pseudo % 360) as f32;
+                lch(0.88, 0.12, hue)
+            }
+            Palette::Custom(custom) => {
+                let (r, g, b) = custom.head;
+                Color::Rgb { r, g, b }
+            }
+        }
+    }
+}
+
+pub fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (u8, u8, u8) {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let hp = h / 60.0;
+    let x = c * (1.0 - (hp % 2.0 - 1.0).abs());
+    let (r1, g1, b1) = match hp as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = l - c / 2.0;
+    (
+        ((r1 + m) * 255.0) as u8,
+        ((g1 + m) * 255.0) as u8,
+        ((b1 + m) * 255.0) as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hsl_primaries() {
+        assert_eq!(hsl_to_rgb(0.0, 1.0, 0.5), (255, 0, 0));
+        assert_eq!(hsl_to_rgb(120.0, 1.0, 0.5), (0, 255, 0));
+        assert_eq!(hsl_to_rgb(240.0, 1.0, 0.5), (0, 0, 255));
+    }
+
+    #[test]
+    fn hsl_secondaries() {
+        assert_eq!(hsl_to_rgb(60.0, 1.0, 0.5), (255, 255, 0));
+        assert_eq!(hsl_to_rgb(180.0, 1.0, 0.5), (0, 255, 255));
+        assert_eq!(hsl_to_rgb(300.0, 1.0, 0.5), (255, 0, 255));
+    }
+
+    #[test]
+    fn hsl_lightness_extremes_and_grays() {
+        assert_eq!(hsl_to_rgb(200.0, 1.0, 0.0), (0, 0, 0));
+        assert_eq!(hsl_to_rgb(200.0, 1.0, 1.0), (255, 255, 255));
+        assert_eq!(hsl_to_rgb(42.0, 0.0, 0.5), (127, 127, 127));
+    }
+
+    #[test]
+    fn hsl_hue_wraps_at_360() {
+        assert_eq!(hsl_to_rgb(360.0, 1.0, 0.5), hsl_to_rgb(0.0, 1.0, 0.5));
+    }
+
+    #[test]
+    fn custom_trails_spread_their_colors_evenly() {
+        let rgb = |r, g, b| Color::Rgb { r, g, b };
+        let trail = [rgb(200, 0, 100), rgb(100, 100, 0), rgb(0, 0, 0)];
+        let palette = Palette::custom("dusk", rgb(255, 255, 255), &trail).unwrap();
+        assert_eq!(palette.name(), "dusk");
+        assert_eq!(palette.head_color(0, 0, 0), rgb(255, 255, 255));
+        assert_eq!(palette.color(1.0, 0, 0, 0), rgb(200, 0, 100));
+        let (r, g, b) = mix((100, 100, 0), (200, 0, 100), 0.5);
+        assert_eq!(palette.color(0.75, 0, 0, 0), rgb(r, g, b));
+        assert_eq!(palette.color(0.5, 0, 0, 0), rgb(100, 100, 0));
+        assert_eq!(palette.color(0.0, 0, 0, 0), rgb(0, 0, 0));
+        assert_eq!(Palette::custom("none", rgb(0, 0, 0), &[]), None);
+    }
+
+    #[test]
+    fn gradient_stops_interpolate_by_position() {
+        let rgb = |r, g, b| Color::Rgb { r, g, b };
+        let stops = [(0.8, rgb(200, 100, 0)), (0.2, rgb(0, 0, 100))];
+        let palette = Palette::gradient("dusk", rgb(255, 255, 255), &stops).unwrap();
+        assert_eq!(palette.color(1.0, 0, 0, 0), rgb(200, 100, 0));
+        let (r, g, b) = mix((0, 0, 100), (200, 100, 0), 0.5);
+        assert_eq!(palette.color(0.5, 0, 0, 0), rgb(r, g, b));
+        assert_eq!(palette.color(0.1, 0, 0, 0), rgb(0, 0, 100));
+        assert_eq!(
+            Palette::gradient("bad", rgb(0, 0, 0), &[(1.5, rgb(0, 0, 0))]),
+            None
+        );
+    }
+
+    #[test]
+    fn trails_fade_from_head_to_tail() {
+        let brightness = |color: Color| match color {
+            Color::Rgb { r, g, b } => r as u32 + g as u32 + b as u32,
+            _ => unreachable!(),
+        };
+        for palette in [
+            Palette::Arcane,
+            Palette::Emerald,
+            Palette::Frost,
+            Palette::Ember,
+            Palette::Rainbow,
+        ] {
+            let head = brightness(palette.color(1.0, 200, 7, 3));
+            let tail = brightness(palette.color(0.0, 200, 7, 3));
+            assert!(head > tail, "{palette:?}");
+        }
+    }
+}
@@ -0,0 +1,733 @@
+use crate::backend::Backend;
+use crate::buffer::{Buffer, Cell};
+use crate::color::ColorMode;
+use crate::config::Config;
+use crate::daylight::Daylight;
+use crate::direction::Direction;
+use crate::lore::{rune_info, RuneInfo};
+use crate::message::Message;
+use crate::oklab::mix;
+use crate::palette::{Background, Palette};
+use crate::runes::{ascii_fallback, RuneSet};
+use crate::stream::Stream;
+use crate::width::str_width;
+use crossterm::style::{Attribute, Attributes, Color};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::io;
+
+// ── Rendering ─────────────────────────────────────────────────────────
+
+pub struct Renderer {
+    cols: u16,
+    rows: u16,
+    direction: Direction,
+    streams: Vec<Stream>,
+    palette: Palette,
+    palettes: Vec<Palette>, // cycle order: built-in palettes, then custom ones
+    background: Option<Background>, // replaces the palettes' own backgrounds
+    daylight: Option<Daylight>, // keeps the palette in step with the time of day
+    transition: u64,        // frames a palette or rune set switch blends over
+    fading: Vec<(Palette, f32)>, // faded-out palettes and how far each fade got
+    fade_left: u64,         // frames left in the palette crossfade
+    reglyph_left: u64,      // frames left for streams to take up the new rune set
+    rune_set: RuneSet,
+    rune_sets: Vec<RuneSet>, // cycle order: built-in sets, then custom ones
+    density: f32,            // fraction of max lanes that have active rain
+    global_tick: u64,
+    show_status: bool,
+    status_timer: u64,      // ticks remaining to show status
+    notice: Option<String>, // shown instead of the status until it hides
+    fps: u64,
+    color_mode: ColorMode,
+    ambiguous_width: u8,
+    ascii: bool,
+    message: Option<Message>,
+    card: bool,                  // show a "rune of the moment" card
+    card_rune: Option<RuneInfo>, // rune on the card, `None` between cards
+    card_timer: u64,             // ticks until the card changes
+    pitch: u16,    // cells per lane / step along x, 2 when the rune set has wide glyphs
+    front: Buffer, // what the terminal currently shows
+    back: Buffer,  // the frame being composed
+    seed: u64,
+    rng: StdRng, // sole source of randomness, so seeded runs replay exactly
+}
+
+impl Renderer {
+    pub fn new(cols: u16, rows: u16, config: &Config) -> Self {
+        let seed = config.seed.unwrap_or_else(rand::random);
+        let ambiguous_width = config.ambiguous_width.clamp(1, 2);
+        let mut rune_sets = RuneSet::BUILTIN.to_vec();
+        for set in config.custom_rune_sets.iter().chain([&config.rune_set]) {
+            if !rune_sets.contains(set) {
+                rune_sets.push(set.clone());
+            }
+        }
+        let mut palettes = Palette::BUILTIN.to_vec();
+        for palette in config.custom_palettes.iter().chain([&config.palette]) {
+            if !palettes.contains(palette) {
+                palettes.push(palette.clone());
+            }
+        }
+        let daylight = config.auto_palette.then_some(Daylight {
+            seasons: config.seasons,
+        });
+        let mut renderer = Renderer {
+            cols,
+            rows,
+            direction: config.direction,
+            streams: Vec::new(),
+            palette: daylight.map_or_else(|| config.palette.clone(), |daylight| daylight.now()),
+            palettes,
+            background: config.background,
+            daylight,
+            transition: config.transition,
+            fading: Vec::new(),
+            fade_left: 0,
+            reglyph_left: 0,
+            rune_set: config.rune_set.clone(),
+            rune_sets,
+            density: config.density,
+            global_tick: 0,
+            show_status: config.show_status,
+            status_timer: config.fps * 3,
+            notice: None,
+            fps: config.fps,
+            color_mode: config.color_mode,
+            ambiguous_width,
+            ascii: config.ascii,
+            message: config.message.as_deref().map(Message::new),
+            card: config.rune_card,
+            card_rune: None,
+            card_timer: 0,
+            pitch: 1,
+            front: Buffer::new(cols, rows).with_ambiguous_width(ambiguous_width),
+            back: Buffer::new(cols, rows).with_ambiguous_width(ambiguous_width),
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        };
+
+        renderer.pitch = renderer.lane_pitch();
+        renderer.spawn_streams();
+        renderer.layout_message();
+        renderer
+    }
+
+    pub fn size(&self) -> (u16, u16) {
+        (self.cols, self.rows)
+    }
+
+    /// Seed of the random generator; pass it back via [`Config::seed`] to
+    /// replay this run.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    /// The time-of-day palette being followed, if any.
+    pub fn daylight(&self) -> Option<Daylight> {
+        self.daylight
+    }
+
+    /// Follow the time of day with `daylight`, or keep the current palette
+    /// with `None`.
+    pub fn set_daylight(&mut self, daylight: Option<Daylight>) {
+        self.daylight = daylight;
+        if let Some(daylight) = daylight {
+            self.fade_to(daylight.now());
+        }
+    }
+
+    /// Switch palettes, crossfading from the current one over the
+    /// transition frames. This stops following the time of day.
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.daylight = None;
+        self.fade_to(palette);
+    }
+
+    fn fade_to(&mut self, palette: Palette) {
+        if palette != self.palette && self.transition > 0 {
+            // A switch during a crossfade starts from the blend on screen,
+            // which stays frozen while the new fade runs
+            let progress = if self.fading.is_empty() {
+                1.0
+            } else {
+                self.fade_progress()
+            };
+            let old = std::mem::replace(&mut self.palette, palette);
+            self.fading.push((old, progress));
+            self.fade_left = self.transition;
+        } else {
+            self.palette = palette;
+        }
+    }
+
+    /// Background color of row `y`, or `None` to leave the terminal's own.
+    /// While crossfading to or from a palette without a background, the
+    /// missing side counts as black.
+    pub fn background(&self, y: u16) -> Option<(u8, u8, u8)> {
+        self.crossfade_rgb(|palette| {
+            let background = self.background.or_else(|| palette.background())?;
+            Some(background.at(y, self.rows))
+        })
+    }
+
+    /// How far the running crossfade has got, from 0.0 to 1.0.
+    fn fade_progress(&self) -> f32 {
+        1.0 - self.fade_left as f32 / self.transition.max(1) as f32
+    }
+
+    /// Switch to the next palette, built-in palettes first, then custom ones.
+    pub fn cycle_palette(&mut self) {
+        let current = self.palettes.iter().position(|p| *p == self.palette);
+        let next = current.map_or(0, |idx| (idx + 1) % self.palettes.len());
+        self.set_palette(self.palettes[next].clone());
+    }
+
+    pub fn rune_set(&self) -> &RuneSet {
+        &self.rune_set
+    }
+
+    /// Switch rune sets; glyphs already on screen turn into the new set
+    /// over the transition frames, or straight away without one.
+    pub fn set_rune_set(&mut self, rune_set: RuneSet) {
+        self.rune_set = rune_set;
+        self.reglyph_left = self.transition;
+        if self.transition == 0 {
+            for stream in &mut self.streams {
+                stream.reglyph(1, &mut self.rng, &self.rune_set);
+            }
+        }
+        let pitch = self.lane_pitch();
+        if pitch != self.pitch {
+            // Lanes have to be laid out again for the new glyph width
+            self.pitch = pitch;
+            self.spawn_streams();
+        }
+        self.layout_message();
+    }
+
+    /// Switch to the next rune set, built-in sets first, then custom ones.
+    pub fn cycle_rune_set(&mut self) {
+        let current = self.rune_sets.iter().position(|set| *set == self.rune_set);
+        let next = current.map_or(0, |idx| (idx + 1) % self.rune_sets.len());
+        self.set_rune_set(self.rune_sets[next].clone());
+    }
+
+    /// The phrase being revealed, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_ref().map(Message::text)
+    }
+
+    /// Reveal `text` in the middle of the screen, or stop with `None`.
+    pub fn set_message(&mut self, text: Option<&str>) {
+        self.message = text.map(Message::new);
+        self.layout_message();
+    }
+
+    /// The rune currently shown on the card, if any.
+    pub fn card(&self) -> Option<RuneInfo> {
+        self.card_rune
+    }
+
+    /// Turn the "rune of the moment" card on or off.
+    pub fn toggle_card(&mut self) {
+        self.card = !self.card;
+        self.card_rune = None;
+        self.card_timer = 0;
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn density(&self) -> f32 {
+        self.density
+    }
+
+    pub fn fps(&self) -> u64 {
+        self.fps
+    }
+
+    pub fn set_fps(&mut self, fps: u64) {
+        self.fps = fps;
+    }
+
+    pub fn color_mode(&self) -> ColorMode {
+        self.color_mode
+    }
+
+    pub fn set_color_mode(&mut self, color_mode: ColorMode) {
+        self.color_mode = color_mode;
+    }
+
+    pub fn ambiguous_width(&self) -> u8 {
+        self.ambiguous_width
+    }
+
+    pub fn ascii(&self) -> bool {
+        self.ascii
+    }
+
+    /// Resize the simulation. The terminal is assumed to have been cleared, so
+    /// the next [`render`](Self::render) repaints every visible cell.
+    pub fn resize(&mut self, new_cols: u16, new_rows: u16) {
+        self.cols = new_cols;
+        self.rows = new_rows;
+        self.front.resize(new_cols, new_rows);
+        self.back.resize(new_cols, new_rows);
+        self.spawn_streams();
+        self.layout_message();
+    }
+
+    fn layout_message(&mut self) {
+        if let Some(message) = &mut self.message {
+            message.layout(
+                self.cols,
+                self.rows,
+                self.pitch,
+                &self.rune_set,
+                self.ambiguous_width,
+            );
+        }
+    }
+
+    fn lane_pitch(&self) -> u16 {
+        if self.ascii {
+            1
+        } else {
+            self.rune_set.max_width(self.ambiguous_width)
+        }
+    }
+
+    /// Size of the grid streams move on: the screen with `pitch` cells per
+    /// column, so wide glyphs never overlap their neighbours.
+    fn grid_size(&self) -> (u16, u16) {
+        (self.cols / self.pitch, self.rows)
+    }
+
+    /// Screen position of the cell at `pos` along `lane`.
+    fn to_screen(&self, lane: u16, pos: i32) -> Option<(u16, u16)> {
+        let (cols, rows) = self.grid_size();
+        let (x, y) = self.direction.to_screen(lane, pos, cols, rows)?;
+        Some((x * self.pitch, y))
+    }
+
+    fn spawn_streams(&mut self) {
+        let (cols, rows) = self.grid_size();
+        let max_lanes = self.direction.max_lanes(cols, rows);
+        let max_pos = self.direction.max_pos(cols, rows);
+        let target = ((max_lanes as f32 * self.density) as usize).max(1);
+
+        self.streams.clear();
+        let mut available: Vec<u16> = (0..max_lanes).collect();
+        for _ in 0..target.min(max_lanes as usize) {
+            if available.is_empty() {
+                break;
+            }
+            let idx = self.rng.gen_range(0..available.len());
+            let lane = available.swap_remove(idx);
+            self.streams
+                .push(Stream::new(lane, max_pos, &mut self.rng, &self.rune_set));
+        }
+    }
+
+    /// Advance the simulation by one frame.
+    pub fn tick(&mut self) {
+        self.global_tick = self.global_tick.wrapping_add(1);
+        self.status_timer = self.status_timer.saturating_sub(1);
+        if self.status_timer == 0 {
+            self.notice = None;
+        }
+
+        let (cols, rows) = self.grid_size();
+        let max_lanes = self.direction.max_lanes(cols, rows);
+        let max_pos = self.direction.max_pos(cols, rows);
+
+        if let Some(daylight) = self.daylight {
+            // The time-of-day colors drift slowly enough to follow once a
+            // second, without a crossfade
+            if self.global_tick.is_multiple_of(self.fps.max(1)) && self.fading.is_empty() {
+                self.palette = daylight.now();
+            }
+        }
+        if self.fade_left > 0 {
+            self.fade_left -= 1;
+            if self.fade_left == 0 {
+                self.fading.clear();
+            }
+        }
+        if self.reglyph_left > 0 {
+            for stream in &mut self.streams {
+                stream.reglyph(self.reglyph_left as u32, &mut self.rng, &self.rune_set);
+            }
+            self.reglyph_left -= 1;
+        }
+
+        let mut occupied = vec![false; max_lanes as usize];
+        for stream in &mut self.streams {
+            stream.tick(max_pos, &mut self.rng, &self.rune_set);
+            if stream.active && (stream.lane as usize) < occupied.len() {
+                occupied[stream.lane as usize] = true;
+            }
+        }
+
+        let mut free_lanes: Vec<u16> = (0..max_lanes).filter(|&l| !occupied[l as usize]).collect();
+
+        for stream in &mut self.streams {
+            if !stream.active {
+                let new_lane = if !free_lanes.is_empty() {
+                    let idx = self.rng.gen_range(0..free_lanes.len());
+                    free_lanes.swap_remove(idx)
+                } else {
+                    self.rng.gen_range(0..max_lanes.max(1))
+                };
+
+                stream.reset(new_lane, max_pos, &mut self.rng, &self.rune_set);
+                if (new_lane as usize) < occupied.len() {
+                    occupied[new_lane as usize] = true;
+                }
+            }
+        }
+
+        if let Some(mut message) = self.message.take() {
+            let heads: Vec<(u16, u16)> = self
+                .streams
+                .iter()
+                .filter(|stream| stream.active)
+                .filter_map(|stream| self.to_screen(stream.lane, stream.pos))
+                .collect();
+            message.tick(&heads, self.fps, &mut self.rng);
+            self.message = Some(message);
+        }
+
+        if self.card {
+            self.card_timer = self.card_timer.saturating_sub(1);
+            if self.card_timer == 0 {
+                self.next_card();
+            }
+        }
+    }
+
+    /// Pick a rune on screen for the card, or take the card down for a
+    /// while after it has been shown.
+    fn next_card(&mut self) {
+        if self.card_rune.take().is_some() {
+            self.card_timer = self.fps * 4;
+            return;
+        }
+        let candidates: Vec<RuneInfo> = self
+            .cells()
+            .filter_map(|(_, cell)| rune_info(&self.rune_set, cell.ch))
+            .collect();
+        if candidates.is_empty() {
+            self.card_timer = self.fps;
+        } else {
+            self.card_rune = Some(candidates[self.rng.gen_range(0..candidates.len())]);
+            self.card_timer = self.fps * 8;
+        }
+    }
+
+    pub fn change_density(&mut self, delta: f32) {
+        self.density = (self.density + delta).clamp(0.05, 1.0);
+        self.spawn_streams();
+    }
+
+    pub fn change_direction(&mut self, new_dir: Direction) {
+        if self.direction != new_dir {
+            self.direction = new_dir;
+            self.spawn_streams();
+        }
+    }
+
+    pub fn poke_status(&mut self) {
+        self.notice = None;
+        self.status_timer = self.fps * 3; // show for 3 seconds
+    }
+
+    /// Show `text` in the status bar for a few seconds, even when the
+    /// status bar is toggled off.
+    pub fn notify(&mut self, text: &str) {
+        self.poke_status();
+        self.notice = Some(text.to_string());
+    }
+
+    pub fn notice(&self) -> Option<&str> {
+        self.notice.as_deref()
+    }
+
+    pub fn toggle_status(&mut self) {
+        self.show_status = !self.show_status;
+        if self.show_status {
+            self.poke_status();
+        }
+    }
+
+    /// Visible cells of every active stream as `((x, y), cell)`, in paint
+    /// order, followed by the revealed glyphs of the message.
+    pub fn cells(&self) -> impl Iterator<Item = ((u16, u16), Cell)> + '_ {
+        let message = self.message.iter().flat_map(move |message| {
+            message.locked().map(move |((x, y), ch)| {
+                let fg = self
+                    .crossfade(|palette| palette.head_color(x as u8, self.global_tick, y as i32));
+                let cell = self.over_background(Cell::new(ch, fg), y, 1.0);
+                if self.color_mode == ColorMode::Mono {
+                    ((x, y), shade(cell, 1.0))
+                } else {
+                    ((x, y), cell)
+                }
+            })
+        });
+        self.streams
+            .iter()
+            .filter(|stream| stream.active)
+            .flat_map(move |stream| {
+                // Tail first so the head glow is painted last
+                (0..stream.trail_len as i32).rev().filter_map(move |i| {
+                    let pos = self.to_screen(stream.lane, stream.pos - i)?;
+                    let intensity = 1.0 - (i as f32 / stream.trail_len as f32);
+                    let fg = self.crossfade(|palette| {
+                        if i == 0 {
+                            palette.head_color(stream.color_seed, self.global_tick, stream.pos)
+                        } else {
+                            palette.color(
+                                intensity,
+                                stream.color_seed,
+                                self.global_tick,
+                                stream.pos,
+                            )
+                        }
+                    });
+                    let ch = stream.chars.get(i as usize).copied().unwrap_or('ᚠ');
+                    let cell = self.over_background(Cell::new(ch, fg), pos.1, intensity);
+                    if self.color_mode == ColorMode::Mono {
+                        Some((pos, shade(cell, intensity)))
+                    } else {
+                        Some((pos, cell))
+                    }
+                })
+            })
+            .chain(message)
+    }
+
+    /// `cell` on row `y` over the background, its color sinking into the
+    /// background along the dimmer half of the trail.
+    fn over_background(&self, cell: Cell, y: u16, intensity: f32) -> Cell {
+        let Some(bg) = self.background(y) else {
+            return cell;
+        };
+        let fg = match cell.fg {
+            Color::Rgb { r, g, b } if intensity < 0.5 => {
+                let (r, g, b) = mix(bg, (r, g, b), intensity * 2.0);
+                Color::Rgb { r, g, b }
+            }
+            fg => fg,
+        };
+        let (r, g, b) = bg;
+        Cell {
+            fg,
+            bg: Color::Rgb { r, g, b },
+            ..cell
+        }
+    }
+
+    /// A color from the current palette, blended with the same color from the
+    /// palette being faded out while a crossfade runs.
+    fn crossfade(&self, color: impl Fn(&Palette) -> Color) -> Color {
+        let new = color(&self.palette);
+        if !matches!(new, Color::Rgb { .. }) {
+            return new;
+        }
+        let rgb = |palette: &Palette| match color(palette) {
+            Color::Rgb { r, g, b } => Some((r, g, b)),
+            _ => None,
+        };
+        match self.crossfade_rgb(rgb) {
+            Some((r, g, b)) => Color::Rgb { r, g, b },
+            None => new,
+        }
+    }
+
+    /// [`crossfade`](Self::crossfade) for colors a palette may not have,
+    /// such as a background: `None` if no palette in the fade has one, and
+    /// black standing in for those that don't if some do.
+    fn crossfade_rgb(
+        &self,
+        rgb: impl Fn(&Palette) -> Option<(u8, u8, u8)>,
+    ) -> Option<(u8, u8, u8)> {
+        let new = rgb(&self.palette);
+        if self.fading.is_empty() {
+            return new;
+        }
+        let colors: Vec<_> = self
+            .fading
+            .iter()
+            .map(|(palette, progress)| (rgb(palette), *progress))
+            .chain([(new, self.fade_progress())])
+            .collect();
+        if colors.iter().all(|(color, _)| color.is_none()) {
+            return None;
+        }
+        // Replay the interrupted fades up to where each was cut short, then
+        // the running one
+        let black = (0, 0, 0);
+        let start = colors[0].0.unwrap_or(black);
+        Some(colors[1..].iter().fold(start, |on_screen, &(color, t)| {
+            mix(on_screen, color.unwrap_or(black), t)
+        }))
+    }
+
+    /// The most recently drawn frame.
+    pub fn buffer(&self) -> &Buffer {
+        &self.back
+    }
+
+    /// Compose the current frame into the back buffer.
+    pub fn draw(&mut self) {
+        let mut back = std::mem::take(&mut self.back);
+        for y in 0..self.rows {
+            // Erased cells show the background rather than a bare space
+            let bg = self.background(y).map_or(Color::Reset, |(r, g, b)| {
+                self.color_mode.quantize(Color::Rgb { r, g, b })
+            });
+            back.fill_row(y, bg);
+        }
+        for ((x, y), cell) in self.cells() {
+            let fg = self.color_mode.quantize(cell.fg);
+            let bg = self.color_mode.quantize(cell.bg);
+            let ch = if self.ascii {
+                ascii_fallback(cell.ch)
+            } else {
+                cell.ch
+            };
+            let mut attrs = cell.attrs;
+            if self.card_rune.is_some_and(|info| info.glyph == cell.ch) {
+                attrs.set(Attribute::Reverse);
+            }
+            back.set(x, y, Cell { ch, fg, bg, attrs });
+        }
+        self.back = back;
+
+        if let Some(info) = self.card_rune {
+            self.draw_card(info);
+        }
+
+        if (self.show_status || self.notice.is_some()) && self.status_timer > 0 && self.rows > 0 {
+            let status = if let Some(notice) = &self.notice {
+                format!(" {notice} ")
+            } else if self.ascii {
+                format!(
+                    " {} | {} | {} FPS | Density: {:.2} ",
+                    self.rune_set.name(),
+                    self.palette.name(),
+                    self.fps,
+                    self.density
+                )
+            } else {
+                format!(
+                    " 🔮 {} | 🎨 {} | ⚡ {} FPS | Density: {:.2} ",
+                    self.rune_set.name(),
+                    self.palette.name(),
+                    self.fps,
+                    self.density
+                )
+            };
+
+            // Draw discrete status bar at bottom right
+            let x = self
+                .cols
+                .saturating_sub(str_width(&status, self.ambiguous_width));
+            let y = self.rows - 1;
+
+            // Fade the text slightly when it's about to disappear
+            let brightness = if self.status_timer < self.fps {
+                50 + (100 * self.status_timer / self.fps) as u8
+            } else {
+                150
+            };
+
+            let fg = self.color_mode.quantize(Color::Rgb {
+                r: brightness,
+                g: brightness,
+                b: brightness,
+            });
+            let mut attrs = Attributes::default();
+            if self.color_mode == ColorMode::Mono && self.status_timer < self.fps {
+                attrs.set(Attribute::Dim);
+            }
+            self.back.set_str(x, y, &status, fg, attrs);
+        }
+    }
+
+    /// The card in the top-left corner: the rune with its name and sound
+    /// value, its meaning and the sets it belongs to, in reverse video.
+    fn draw_card(&mut self, info: RuneInfo) {
+        let glyph = if self.ascii {
+            ascii_fallback(info.glyph)
+        } else {
+            info.glyph
+        };
+        let title = if info.sound.is_empty() {
+            format!(" {glyph}  {} ", info.name)
+        } else {
+            format!(" {glyph}  {}  /{}/ ", info.name, info.sound)
+        };
+        let sets: Vec<String> = info
+            .sets()
+            .iter()
+            .map(|set| set.name().to_string())
+            .collect();
+        let lines = [
+            title,
+            format!(" {} ", info.meaning),
+            format!(" {} ", sets.join(", ")),
+        ];
+
+        let width = lines
+            .iter()
+            .map(|line| str_width(line, self.ambiguous_width))
+            .max()
+            .unwrap_or(0);
+        let fg = self
+            .color_mode
+            .quantize(self.palette.head_color(0, self.global_tick, 0));
+        let mut attrs = Attributes::default();
+        attrs.set(Attribute::Reverse);
+        for (row, line) in lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+        {
+            let pad = width - str_width(line, self.ambiguous_width);
+            let line = format!("{line}{}", " ".repeat(pad as usize));
+            self.back.set_str(1, 1 + row as u16, &line, fg, attrs);
+        }
+    }
+
+    /// Draw the current frame and send only the cells that changed since the
+    /// previous call to `backend`.
+    pub fn render(&mut self, backend: &mut impl Backend) -> io::Result<()> {
+        self.draw();
+        self.back.diff(&self.front, backend)?;
+        self.front.clone_from(&self.back);
+        backend.flush()
+    }
+}
+
+/// Convey trail intensity without color: a bold head, a normal body, a dim
+/// tail whose last stretch fades to a runic dot.
+fn shade(cell: Cell, intensity: f32) -> Cell {
+    let mut cell = cell;
+    if intensity >= 1.0 {
+        cell.attrs.set(Attribute::Bold);
+    } else if intensity < 0.15 {
+        cell.ch = '᛫';
+        cell.attrs.set(Attribute::Dim);
+    } else if intensity < 0.5 {
+        cell.attrs.set(Attribute::Dim);
+    }
+    cell
+}
@@ -0,0 +1,693 @@
+use crate::width::glyph_width;
+use rand::Rng;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use unicode_width::UnicodeWidthChar;
+
+// ── Runic character sets ──────────────────────────────────────────────
+
+pub const ELDER_FUTHARK: &[char] = &[
+    'ᚠ', 'ᚢ', 'ᚦ', 'ᚨ', 'ᚱ', 'ᚲ', 'ᚷ', 'ᚹ', 'ᚺ', 'ᚾ', 'ᛁ', 'ᛃ', 'ᛇ', 'ᛈ', 'ᛉ', 'ᛊ', 'ᛋ', 'ᛏ', 'ᛒ',
+    'ᛖ', 'ᛗ', 'ᛚ', 'ᛜ', 'ᛝ', 'ᛞ', 'ᛟ',
+];
+
+pub const YOUNGER_FUTHARK: &[char] = &[
+    'ᚠ', 'ᚢ', 'ᚦ', 'ᚬ', 'ᚱ', 'ᚴ', 'ᚼ', 'ᚾ', 'ᛁ', 'ᛅ', 'ᛋ', 'ᛏ', 'ᛒ', 'ᛘ', 'ᛚ', 'ᛦ',
+];
+
+pub const ANGLO_SAXON: &[char] = &[
+    'ᚠ', 'ᚢ', 'ᚦ', 'ᚩ', 'ᚱ', 'ᚳ', 'ᚷ', 'ᚹ', 'ᚻ', 'ᚾ', 'ᛁ', 'ᛄ', 'ᛇ', 'ᛈ', 'ᛉ', 'ᛋ', 'ᛏ', 'ᛒ', 'ᛖ',
+    'ᛗ', 'ᛚ', 'ᛝ', 'ᛟ', 'ᛡ', 'ᛣ', 'ᛥ',
+];
+
+pub const OGHAM: &[char] = &[
+    'ᚁ', 'ᚂ', 'ᚃ', 'ᚄ', 'ᚅ', 'ᚆ', 'ᚇ', 'ᚈ', 'ᚉ', 'ᚊ', 'ᚋ', 'ᚌ', 'ᚍ', 'ᚎ', 'ᚏ', 'ᚐ', 'ᚑ', 'ᚒ', 'ᚓ',
+    'ᚔ', 'ᚕ', 'ᚖ', 'ᚗ', 'ᚘ', 'ᚙ', 'ᚚ',
+];
+
+pub const MYSTIC: &[char] = &[
+    '☽', '☾', '✧', '✦', '◈', '◇', '⁂', '⊕', '⊗', '⊛', '⌘', '⍟', '♅', '♆', '♇', '⚝', '✡', '⬡', '⬢',
+    '⏣', '⏥', '◉', '◎', '⦿',
+];
+
+/// Medieval futhork: Younger Futhark short-twig forms plus dotted runes.
+pub const MEDIEVAL: &[char] = &[
+    'ᚠ', 'ᚡ', 'ᚢ', 'ᚤ', 'ᚦ', 'ᚧ', 'ᚮ', 'ᚯ', 'ᚱ', 'ᚴ', 'ᚵ', 'ᚼ', 'ᚽ', 'ᚿ', 'ᛀ', 'ᛁ', 'ᛂ', 'ᛆ', 'ᛌ',
+    'ᛍ', 'ᛎ', 'ᛐ', 'ᛑ', 'ᛒ', 'ᛓ', 'ᛔ', 'ᛕ', 'ᛘ', 'ᛙ', 'ᛚ', 'ᛛ', 'ᛦ', 'ᛩ', 'ᛪ',
+];
+/// Orkhon and Yenisei inscriptions.
+pub const OLD_TURKIC: &[char] = &[
+    '𐰀', '𐰁', '𐰂', '𐰃', '𐰄', '𐰅', '𐰆', '𐰇', '𐰈', '𐰉', '𐰊', '𐰋', '𐰌', '𐰍', '𐰎', '𐰏', '𐰐', '𐰑', '𐰒',
+    '𐰓', '𐰔', '𐰕', '𐰖', '𐰗', '𐰘', '𐰙', '𐰚', '𐰛', '𐰜', '𐰝', '𐰞', '𐰟', '𐰠', '𐰡', '𐰢', '𐰣', '𐰤', '𐰥',
+    '𐰦', '𐰧', '𐰨', '𐰩', '𐰪', '𐰫', '𐰬', '𐰭', '𐰮', '𐰯', '𐰰', '𐰱', '𐰲', '𐰳', '𐰴', '𐰵', '𐰶', '𐰷', '𐰸',
+    '𐰹', '𐰺', '𐰻', '𐰼', '𐰽', '𐰾', '𐰿', '𐱀', '𐱁', '𐱂', '𐱃', '𐱄', '𐱅', '𐱆', '𐱇', '𐱈',
+];
+/// Etruscan, Oscan, Umbrian and related alphabets.
+pub const OLD_ITALIC: &[char] = &[
+    '𐌀', '𐌁', '𐌂', '𐌃', '𐌄', '𐌅', '𐌆', '𐌇', '𐌈', '𐌉', '𐌊', '𐌋', '𐌌', '𐌍', '𐌎', '𐌏', '𐌐', '𐌑', '𐌒',
+    '𐌓', '𐌔', '𐌕', '𐌖', '𐌗', '𐌘', '𐌙', '𐌚', '𐌛', '𐌜', '𐌝', '𐌞', '𐌟',
+];
+/// Wulfila's Gothic alphabet.
+pub const GOTHIC: &[char] = &[
+    '𐌰', '𐌱', '𐌲', '𐌳', '𐌴', '𐌵', '𐌶', '𐌷', '𐌸', '𐌹', '𐌺', '𐌻', '𐌼', '𐌽', '𐌾', '𐌿', '𐍀', '𐍁', '𐍂',
+    '𐍃', '𐍄', '𐍅', '𐍆', '𐍇', '𐍈', '𐍉', '𐍊',
+];
+/// Székely-Hungarian rovás, capital letters.
+pub const OLD_HUNGARIAN: &[char] = &[
+    '𐲀', '𐲁', '𐲂', '𐲃', '𐲄', '𐲅', '𐲆', '𐲇', '𐲈', '𐲉', '𐲊', '𐲋', '𐲌', '𐲍', '𐲎', '𐲏', '𐲐', '𐲑', '𐲒',
+    '𐲓', '𐲔', '𐲕', '𐲖', '𐲗', '𐲘', '𐲙', '𐲚', '𐲛', '𐲜', '𐲝', '𐲞', '𐲟', '𐲠', '𐲡', '𐲢', '𐲣', '𐲤', '𐲥',
+    '𐲦', '𐲧', '𐲨', '𐲩', '𐲪', '𐲫', '𐲬', '𐲭', '𐲮', '𐲯', '𐲰', '𐲱', '𐲲',
+];
+/// Neo-Tifinagh and Tuareg letters.
+pub const TIFINAGH: &[char] = &[
+    'ⴰ', 'ⴱ', 'ⴲ', 'ⴳ', 'ⴴ', 'ⴵ', 'ⴶ', 'ⴷ', 'ⴸ', 'ⴹ', 'ⴺ', 'ⴻ', 'ⴼ', 'ⴽ', 'ⴾ', 'ⴿ', 'ⵀ', 'ⵁ', 'ⵂ',
+    'ⵃ', 'ⵄ', 'ⵅ', 'ⵆ', 'ⵇ', 'ⵈ', 'ⵉ', 'ⵊ', 'ⵋ', 'ⵌ', 'ⵍ', 'ⵎ', 'ⵏ', 'ⵐ', 'ⵑ', 'ⵒ', 'ⵓ', 'ⵔ', 'ⵕ',
+    'ⵖ', 'ⵗ', 'ⵘ', 'ⵙ', 'ⵚ', 'ⵛ', 'ⵜ', 'ⵝ', 'ⵞ', 'ⵟ', 'ⵠ', 'ⵡ', 'ⵢ', 'ⵣ', 'ⵤ', 'ⵥ', 'ⵦ', 'ⵧ',
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RuneSet {
+    All,
+    Elder,
+    Younger,
+    Anglo,
+    Ogham,
+    Mystic,
+    Medieval,
+    OldTurkic,
+    OldItalic,
+    Gothic,
+    OldHungarian,
+    Tifinagh,
+    Custom(Arc<CustomSet>),
+}
+
+/// A user-defined glyph set, e.g. company glyphs or a PUA font's Cirth,
+/// or a weighted mix of other sets.
+#[derive(Debug, PartialEq)]
+pub struct CustomSet {
+    name: String,
+    glyphs: Vec<char>,
+    cumulative: Vec<f32>, // running glyph weights; empty when all are equal
+}
+
+impl CustomSet {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn glyphs(&self) -> &[char] {
+        &self.glyphs
+    }
+
+    /// Relative weight of each glyph, in the order of [`CustomSet::glyphs`].
+    pub fn weights(&self) -> impl Iterator<Item = f32> + '_ {
+        (0..self.glyphs.len()).map(|idx| match idx {
+            _ if self.cumulative.is_empty() => 1.0,
+            0 => self.cumulative[0],
+            _ => self.cumulative[idx] - self.cumulative[idx - 1],
+        })
+    }
+
+    fn sample(&self, rng: &mut impl Rng) -> char {
+        let idx = match self.cumulative.last() {
+            Some(&total) => {
+                let x = rng.gen_range(0.0..total);
+                let idx = self.cumulative.partition_point(|&running| running <= x);
+                idx.min(self.glyphs.len() - 1)
+            }
+            None => rng.gen_range(0..self.glyphs.len()),
+        };
+        self.glyphs[idx]
+    }
+}
+
+impl RuneSet {
+    /// Built-in sets in cycle order.
+    pub const BUILTIN: [RuneSet; 12] = [
+        RuneSet::All,
+        RuneSet::Elder,
+        RuneSet::Younger,
+        RuneSet::Anglo,
+        RuneSet::Ogham,
+        RuneSet::Mystic,
+        RuneSet::Medieval,
+        RuneSet::OldTurkic,
+        RuneSet::OldItalic,
+        RuneSet::Gothic,
+        RuneSet::OldHungarian,
+        RuneSet::Tifinagh,
+    ];
+
+    pub fn name(&self) -> &str {
+        match self {
+            RuneSet::All => "All",
+            RuneSet::Elder => "Elder Futhark",
+            RuneSet::Younger => "Younger Futhark",
+            RuneSet::Anglo => "Anglo-Saxon",
+            RuneSet::Ogham => "Ogham",
+            RuneSet::Mystic => "Mystic",
+            RuneSet::Medieval => "Medieval Runes",
+            RuneSet::OldTurkic => "Old Turkic",
+            RuneSet::OldItalic => "Old Italic",
+            RuneSet::Gothic => "Gothic",
+            RuneSet::OldHungarian => "Old Hungarian",
+            RuneSet::Tifinagh => "Tifinagh",
+            RuneSet::Custom(custom) => custom.name(),
+        }
+    }
+
+    /// Look up a built-in set by name, e.g. `elder`, `ogham` or `old-turkic`.
+    pub fn from_name(s: &str) -> Option<RuneSet> {
+        match s.to_lowercase().replace(['_', ' '], "-").as_str() {
+            "all" | "mixed" => Some(RuneSet::All),
+            "elder" | "elder-futhark" => Some(RuneSet::Elder),
+            "younger" | "younger-futhark" => Some(RuneSet::Younger),
+            "anglo" | "anglo-saxon" | "futhorc" => Some(RuneSet::Anglo),
+            "ogham" => Some(RuneSet::Ogham),
+            "mystic" => Some(RuneSet::Mystic),
+            "medieval" | "medieval-runes" | "futhork" => Some(RuneSet::Medieval),
+            "turkic" | "old-turkic" | "orkhon" => Some(RuneSet::OldTurkic),
+            "italic" | "old-italic" | "etruscan" => Some(RuneSet::OldItalic),
+            "gothic" => Some(RuneSet::Gothic),
+            "hungarian" | "old-hungarian" | "rovas" => Some(RuneSet::OldHungarian),
+            "tifinagh" => Some(RuneSet::Tifinagh),
+            _ => None,
+        }
+    }
+
+    /// A custom set from free text: every non-whitespace character is a glyph,
+    /// lines starting with `#` are comments and `\#` starts a line with the
+    /// glyph `#`. A word of one glyph followed by `:N`, e.g. `ᚠ:3`, makes that
+    /// glyph N times as likely. Zero-width characters and duplicates are
+    /// dropped. Returns `None` if no glyphs remain.
+    pub fn custom(name: &str, text: &str) -> Option<RuneSet> {
+        let mut glyphs: Vec<(char, f32)> = Vec::new();
+        for line in text.lines() {
+            let line = line.trim_start();
+            if line.starts_with('#') {
+                continue;
+            }
+            let line = line
+                .strip_prefix('\\')
+                .filter(|rest| rest.starts_with('#'))
+                .unwrap_or(line);
+            for word in line.split_whitespace() {
+                let weighted = word.split_once(':').and_then(|(glyph, weight)| {
+                    let mut chars = glyph.chars();
+                    let ch = chars.next().filter(|_| chars.next().is_none())?;
+                    Some((ch, parse_weight(weight)?))
+                });
+                let word_glyphs: Vec<(char, f32)> = match weighted {
+                    Some(glyph) => vec![glyph],
+                    None => word.chars().map(|ch| (ch, 1.0)).collect(),
+                };
+                for (ch, weight) in word_glyphs {
+                    if ch.width().unwrap_or(0) > 0 && glyphs.iter().all(|&(seen, _)| seen != ch) {
+                        glyphs.push((ch, weight));
+                    }
+                }
+            }
+        }
+        RuneSet::weighted(name, glyphs)
+    }
+
+    /// A custom set drawing each glyph with the given relative weight; weights
+    /// of repeated glyphs add up. Returns `None` if no glyph has a weight.
+    pub fn weighted(name: &str, glyphs: impl IntoIterator<Item = (char, f32)>) -> Option<RuneSet> {
+        let mut merged: Vec<(char, f32)> = Vec::new();
+        for (ch, weight) in glyphs {
+            if !(weight > 0.0 && weight.is_finite()) {
+                continue;
+            }
+            match merged.iter_mut().find(|(seen, _)| *seen == ch) {
+                Some((_, total)) => *total += weight,
+                None => merged.push((ch, weight)),
+            }
+        }
+        if merged.is_empty() {
+            return None;
+        }
+
+        let uniform = merged.iter().all(|&(_, weight)| weight == merged[0].1);
+        let cumulative = if uniform {
+            Vec::new()
+        } else {
+            merged
+                .iter()
+                .scan(0.0, |running, &(_, weight)| {
+                    *running += weight;
+                    Some(*running)
+                })
+                .collect()
+        };
+        Some(RuneSet::Custom(Arc::new(CustomSet {
+            name: name.to_string(),
+            glyphs: merged.into_iter().map(|(ch, _)| ch).collect(),
+            cumulative,
+        })))
+    }
+
+    /// Parse a `--runes` spec: a built-in name, a rune set file, or a weighted
+    /// mix of those such as `elder:3,mystic:1`. Each part of a mix contributes
+    /// its weight spread over its glyphs, so `elder,younger` draws every glyph
+    /// of both futharks equally often.
+    pub fn from_spec(spec: &str) -> io::Result<RuneSet> {
+        let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidInput, msg);
+        let parts: Vec<(&str, Option<f32>)> = spec
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(|part| match part.rsplit_once(':') {
+                Some((name, weight)) if weight.parse::<f32>().is_ok() => {
+                    let weight = parse_weight(weight).ok_or_else(|| {
+                        invalid(format!("weight of `{name}` must be a positive number"))
+                    })?;
+                    Ok((name.trim(), Some(weight)))
+                }
+                _ => Ok((part, None)),
+            })
+            .collect::<io::Result<_>>()?;
+
+        let resolve = |name: &str| match RuneSet::from_name(name) {
+            Some(set) => Ok(set),
+            None => RuneSet::from_file(Path::new(name)),
+        };
+        match parts.as_slice() {
+            [] => Err(invalid("empty rune set spec".to_string())),
+            [(name, None)] => resolve(name),
+            _ => {
+                let mut glyphs = Vec::new();
+                let mut names = Vec::new();
+                for &(name, weight) in &parts {
+                    let set = resolve(name)?;
+                    let weight = weight.unwrap_or(1.0);
+                    let set_glyphs = set.weighted_glyphs();
+                    let total: f32 = set_glyphs.iter().map(|&(_, w)| w).sum();
+                    glyphs.extend(
+                        set_glyphs
+                            .into_iter()
+                            .map(|(ch, w)| (ch, w * weight / total)),
+                    );
+                    names.push(match weight {
+                        1.0 => set.name().to_string(),
+                        _ => format!("{}:{weight}", set.name()),
+                    });
+                }
+                RuneSet::weighted(&names.join(" + "), glyphs)
+                    .ok_or_else(|| invalid(format!("no glyphs in `{spec}`")))
+            }
+        }
+    }
+
+    /// Load a custom set from a text file (see [`RuneSet::custom`]), named
+    /// after the file.
+    pub fn from_file(path: &Path) -> io::Result<RuneSet> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "Custom".to_string());
+        RuneSet::custom(&name, &text).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("no glyphs in {}", path.display()),
+            )
+        })
+    }
+
+    /// The built-in glyph tables this set draws from; empty for custom sets.
+    pub fn tables(&self) -> &'static [&'static [char]] {
+        match self {
+            RuneSet::All => &[ELDER_FUTHARK, YOUNGER_FUTHARK, ANGLO_SAXON, OGHAM, MYSTIC],
+            RuneSet::Elder => &[ELDER_FUTHARK],
+            RuneSet::Younger => &[YOUNGER_FUTHARK],
+            RuneSet::Anglo => &[ANGLO_SAXON],
+            RuneSet::Ogham => &[OGHAM],
+            RuneSet::Mystic => &[MYSTIC],
+            RuneSet::Medieval => &[MEDIEVAL],
+            RuneSet::OldTurkic => &[OLD_TURKIC],
+            RuneSet::OldItalic => &[OLD_ITALIC],
+            RuneSet::Gothic => &[GOTHIC],
+            RuneSet::OldHungarian => &[OLD_HUNGARIAN],
+            RuneSet::Tifinagh => &[TIFINAGH],
+            RuneSet::Custom(_) => &[],
+        }
+    }
+
+    /// Every glyph this set can produce, each once even if several of its
+    /// tables share it.
+    pub fn glyphs(&self) -> impl Iterator<Item = char> + '_ {
+        let custom: &[char] = match self {
+            RuneSet::Custom(custom) => custom.glyphs(),
+            _ => &[],
+        };
+        let tables = self.tables();
+        tables
+            .iter()
+            .enumerate()
+            .flat_map(move |(idx, table)| {
+                table
+                    .iter()
+                    .filter(move |ch| !in_earlier_table(tables, idx, **ch))
+            })
+            .chain(custom)
+            .copied()
+    }
+
+    /// Whether `ch` is one of this set's glyphs.
+    pub fn contains(&self, ch: char) -> bool {
+        self.glyphs().any(|glyph| glyph == ch)
+    }
+
+    /// Every glyph with its relative weight; built-in glyphs are equally likely.
+    fn weighted_glyphs(&self) -> Vec<(char, f32)> {
+        match self {
+            RuneSet::Custom(custom) => custom
+                .glyphs()
+                .iter()
+                .copied()
+                .zip(custom.weights())
+                .collect(),
+            _ => self.glyphs().map(|ch| (ch, 1.0)).collect(),
+        }
+    }
+
+    /// Widest glyph of the set in cells, see [`glyph_width`].
+    pub fn max_width(&self, ambiguous_width: u8) -> u16 {
+        self.glyphs()
+            .map(|ch| glyph_width(ch, ambiguous_width))
+            .max()
+            .unwrap_or(1)
+    }
+}
+
+/// A positive, finite weight such as `3` or `0.5`.
+fn parse_weight(s: &str) -> Option<f32> {
+    s.parse()
+        .ok()
+        .filter(|weight: &f32| *weight > 0.0 && weight.is_finite())
+}
+
+/// Whether `ch` is in one of `tables` before the one at `idx`.
+fn in_earlier_table(tables: &[&[char]], idx: usize, ch: char) -> bool {
+    tables[..idx].iter().any(|table| table.contains(&ch))
+}
+
+/// Draw a glyph from `set`. Built-in glyphs are equally likely across all of
+/// the set's tables, so a mix isn't skewed towards its smaller alphabets, nor
+/// towards glyphs that several of its tables share.
+pub fn random_rune(rng: &mut impl Rng, set: &RuneSet) -> char {
+    if let RuneSet::Custom(custom) = set {
+        return custom.sample(rng);
+    }
+    let tables = set.tables();
+    let total = tables.iter().map(|table| table.len()).sum::<usize>();
+    loop {
+        // A glyph repeated from an earlier table is drawn again, so every
+        // distinct glyph is left with the same chance
+        let mut idx = rng.gen_range(0..total);
+        for (table_idx, table) in tables.iter().enumerate() {
+            if idx < table.len() {
+                if !in_earlier_table(tables, table_idx, table[idx]) {
+                    return table[idx];
+                }
+                break;
+            }
+            idx -= table.len();
+        }
+    }
+}
+
+/// Latin stand-ins for the other historic alphabets, by position in the table.
+const TRANSLITERATIONS: &[(&[char], &str)] = &[
+    (
+        OLD_TURKIC,
+        "aaaiieooobbbbggggdddzzyyyykkkkllllmnnnnnccnnnnnppcccqqqqqqrrrssxxxxtttttb",
+    ),
+    (OLD_ITALIC, "abcdevzhqiklmnxopsqrstuxfkfrciuz"),
+    (GOTHIC, "abgdeqzhtiklmnjup9rstwfxvo9"),
+    (
+        OLD_HUNGARIAN,
+        "aabbcccddeeefgghiijkkkllmnnooooopprrssttthuuuuvzznu",
+    ),
+    (
+        TIFINAGH,
+        "abbggjjddddefkkkhhhhaxxqqizzzlmnnnpurrgggsssttctvwyzzzeo",
+    ),
+];
+
+/// ASCII stand-in for a glyph, for fonts without Runic or Ogham coverage.
+/// Runes become upper-case Latin letters by sound value, except thorn, which
+/// takes the lower-case p it looks like; Ogham and the other historic
+/// alphabets become lower-case letters, and mystic symbols look-alike
+/// punctuation.
+pub fn ascii_fallback(ch: char) -> char {
+    match ch {
+        // Futhark runes (shared between Elder, Younger and Anglo-Saxon)
+        'ᚠ' => 'F',
+        'ᚢ' => 'U',
+        'ᚦ' => 'p', // thorn, þ, which has no letter of its own
+        'ᚨ' | 'ᚩ' | 'ᛅ' => 'A',
+        'ᚬ' => 'O', // áss, ã
+        'ᚱ' => 'R',
+        'ᚲ' | 'ᚳ' | 'ᚴ' => 'K',
+        'ᚷ' => 'G',
+        'ᚹ' => 'W',
+        'ᚺ' | 'ᚻ' | 'ᚼ' => 'H',
+        'ᚾ' => 'N',
+        'ᛁ' => 'I',
+        'ᛃ' | 'ᛄ' => 'J',
+        'ᛇ' => 'Y',
+        'ᛈ' => 'P',
+        'ᛉ' | 'ᛦ' => 'Z',
+        'ᛊ' | 'ᛋ' => 'S',
+        'ᛏ' => 'T',
+        'ᛒ' => 'B',
+        'ᛖ' => 'E',
+        'ᛗ' | 'ᛘ' => 'M',
+        'ᛚ' => 'L',
+        'ᛜ' | 'ᛝ' => 'Q', // ing, ŋ
+        'ᛞ' => 'D',
+        'ᛟ' => 'O',
+        'ᛡ' => 'X',
+        'ᛣ' => 'C',
+        'ᛥ' => 'V',
+        // Futhorc letters only used when spelling text
+        'ᚪ' | 'ᚫ' => 'A', // āc, æsc
+        'ᚣ' => 'Y',       // ȳr
+        'ᛠ' => 'E',       // ēar
+        'ᛢ' => 'Q',       // cweorð
+        // Medieval dotted and short-twig runes
+        'ᚡ' => 'V',
+        'ᚤ' => 'Y',
+        'ᚧ' => 'D', // eth, ð
+        'ᚮ' | 'ᚯ' => 'O',
+        'ᚵ' => 'G',
+        'ᚽ' => 'H',
+        'ᚿ' | 'ᛀ' => 'N',
+        'ᛂ' => 'E',
+        'ᛆ' => 'A',
+        'ᛌ' => 'S',
+        'ᛍ' => 'C',
+        'ᛎ' => 'Z',
+        'ᛐ' => 'T',
+        'ᛑ' => 'D',
+        'ᛓ' => 'B',
+        'ᛔ' | 'ᛕ' => 'P',
+        'ᛙ' => 'M',
+        'ᛛ' => 'L',
+        'ᛩ' => 'Q',
+        'ᛪ' => 'X',
+        '᛫' => '.',
+        // Ogham
+        'ᚁ' => 'b',
+        'ᚂ' => 'l',
+        'ᚃ' => 'v',
+        'ᚄ' => 's',
+        'ᚅ' => 'n',
+        'ᚆ' => 'h',
+        'ᚇ' => 'd',
+        'ᚈ' => 't',
+        'ᚉ' => 'c',
+        'ᚊ' => 'q',
+        'ᚋ' => 'm',
+        'ᚌ' => 'g',
+        'ᚍ' => 'w', // ngéadal, ng
+        'ᚎ' => 'z',
+        'ᚏ' => 'r',
+        'ᚐ' => 'a',
+        'ᚑ' => 'o',
+        'ᚒ' => 'u',
+        'ᚓ' => 'e',
+        'ᚔ' => 'i',
+        'ᚕ' => 'k', // ébad, ea
+        'ᚖ' => 'y', // óir, oi
+        'ᚗ' => 'j', // uillenn, ui
+        'ᚘ' => 'f', // ifín, ia
+        'ᚙ' => 'x', // emancholl, ae
+        'ᚚ' => 'p',
+        // Mystic symbols
+        '☽' => '(',
+        '☾' => ')',
+        '✧' => '+',
+        '✦' => '*',
+        '◈' | '⬢' | '⏣' => '#',
+        '◇' | '⬡' | '⏥' => '%',
+        '⁂' => ':',
+        '⊕' | '◉' | '⦿' => '@',
+        '⊗' | '⊛' | '✡' => '&',
+        '⌘' => '$',
+        '⍟' | '⚝' => '^',
+        '♅' | '♆' | '♇' => '~',
+        '◎' => 'o',
+        _ if ch.is_ascii() => ch,
+        _ => TRANSLITERATIONS
+            .iter()
+            .find_map(|(table, latin)| {
+                let idx = table.iter().position(|&glyph| glyph == ch)?;
+                latin.chars().nth(idx)
+            })
+            .unwrap_or('?'),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn every_glyph_has_a_printable_ascii_fallback() {
+        for ch in RuneSet::BUILTIN.iter().flat_map(|set| set.glyphs()) {
+            let fallback = ascii_fallback(ch);
+            assert!(fallback.is_ascii_graphic(), "{ch}");
+            assert_ne!(fallback, '?', "{ch}");
+        }
+    }
+
+    #[test]
+    fn futhark_maps_to_upper_case_and_ogham_to_lower_case() {
+        let fehu: String = "ᚠᚢᚦᚨᚱᚲ".chars().map(ascii_fallback).collect();
+        assert_eq!(fehu, "FUpARK");
+        assert_eq!(ascii_fallback('ᛞ'), 'D');
+        let beith: String = "ᚁᚂᚃᚄᚅ".chars().map(ascii_fallback).collect();
+        assert_eq!(beith, "blvsn");
+    }
+
+    #[test]
+    fn runes_of_a_futhark_or_ogham_have_their_own_fallbacks() {
+        // Variant forms of one rune, such as the two sōwilō, may share one
+        for set in [
+            RuneSet::Elder,
+            RuneSet::Younger,
+            RuneSet::Anglo,
+            RuneSet::Ogham,
+        ] {
+            let glyphs: Vec<char> = set.glyphs().collect();
+            for (idx, &a) in glyphs.iter().enumerate() {
+                for &b in &glyphs[idx + 1..] {
+                    let sound = |ch| crate::lore::rune_info(&set, ch).unwrap().sound;
+                    assert!(
+                        ascii_fallback(a) != ascii_fallback(b) || sound(a) == sound(b),
+                        "{a} and {b} in {} both fall back to {}",
+                        set.name(),
+                        ascii_fallback(a)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transliterations_cover_their_tables() {
+        for (table, latin) in TRANSLITERATIONS {
+            assert_eq!(table.len(), latin.chars().count(), "{latin}");
+        }
+        let gothic: String = GOTHIC[..4].iter().map(|&ch| ascii_fallback(ch)).collect();
+        assert_eq!(gothic, "abgd");
+    }
+
+    #[test]
+    fn builtin_sets_are_found_by_name() {
+        for set in RuneSet::BUILTIN {
+            assert_eq!(RuneSet::from_name(set.name()), Some(set.clone()));
+        }
+        assert_eq!(RuneSet::from_name("old_turkic"), Some(RuneSet::OldTurkic));
+        assert_eq!(RuneSet::from_name("klingon"), None);
+    }
+
+    #[test]
+    fn custom_sets_skip_comments_whitespace_and_duplicates() {
+        let text = "# Company glyphs\n★ ☆ ★\n  ♠♣\u{0301}\n";
+        let set = RuneSet::custom("corp", text).unwrap();
+        assert_eq!(set.name(), "corp");
+        assert_eq!(set.glyphs().collect::<String>(), "★☆♠♣");
+        let hashes = RuneSet::custom("hashes", "\\# \\\n  \\#:2").unwrap();
+        assert_eq!(hashes.glyphs().collect::<String>(), "#\\");
+    }
+
+    #[test]
+    fn custom_sets_need_at_least_one_glyph() {
+        assert_eq!(RuneSet::custom("empty", "# nothing here\n \t\n"), None);
+    }
+
+    #[test]
+    fn custom_sets_take_per_glyph_weights() {
+        let set = RuneSet::custom("odds", "ᚠ:3 ᚢ ★:0.5 :2 a:b").unwrap();
+        let RuneSet::Custom(custom) = &set else {
+            panic!("not a custom set");
+        };
+        assert_eq!(custom.glyphs().iter().collect::<String>(), "ᚠᚢ★:2ab");
+        let weights: Vec<f32> = custom.weights().collect();
+        assert_eq!(weights, [3.0, 1.0, 0.5, 1.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn specs_mix_sets_by_weight() {
+        assert_eq!(RuneSet::from_spec("elder").unwrap(), RuneSet::Elder);
+        let mix = RuneSet::from_spec("elder:3, mystic").unwrap();
+        assert_eq!(mix.name(), "Elder Futhark:3 + Mystic");
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let elder = (0..4000)
+            .filter(|_| ELDER_FUTHARK.contains(&random_rune(&mut rng, &mix)))
+            .count();
+        assert!((2800..3200).contains(&elder), "{elder}");
+
+        assert!(RuneSet::from_spec("elder:0,mystic").is_err());
+        assert!(RuneSet::from_spec("elder,klingon").is_err());
+        assert!(RuneSet::from_spec(" , ").is_err());
+    }
+
+    #[test]
+    fn all_draws_every_glyph_equally_often() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let total = RuneSet::All.glyphs().count() as f32;
+        let younger = (0..8000)
+            .filter(|_| random_rune(&mut rng, &RuneSet::All) == 'ᛦ')
+            .count() as f32;
+        // ᛦ only appears in Younger Futhark, one glyph out of `total`
+        assert!((younger / 8000.0 - 1.0 / total).abs() < 0.006, "{younger}");
+        // ᚠ is in all three futharks but still counts once
+        let fehu = (0..8000)
+            .filter(|_| random_rune(&mut rng, &RuneSet::All) == 'ᚠ')
+            .count() as f32;
+        assert!((fehu / 8000.0 - 1.0 / total).abs() < 0.006, "{fehu}");
+        assert_eq!(RuneSet::All.glyphs().filter(|&ch| ch == 'ᚠ').count(), 1);
+    }
+
+    #[test]
+    fn random_runes_come_from_the_set() {
+        let mut rng = rand::thread_rng();
+        let set = RuneSet::custom("pair", "ab").unwrap();
+        for _ in 0..50 {
+            assert!("ab".contains(random_rune(&mut rng, &set)));
+            assert!(ELDER_FUTHARK.contains(&random_rune(&mut rng, &RuneSet::Elder)));
+        }
+    }
+}
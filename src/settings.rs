@@ -0,0 +1,643 @@
+use crate::color::{parse_hex, ColorMode};
+use crate::config::Config;
+use crate::direction::Direction;
+use crate::keys::{parse_key, Action};
+use crate::palette::{Background, Palette};
+use crate::renderer::Renderer;
+use crate::runes::RuneSet;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use toml::{Spanned, Table, Value};
+
+// ── Config file ───────────────────────────────────────────────────────
+
+/// Layout of `config.toml`. Unknown keys are an error rather than silently
+/// ignored, so typos get reported.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct File {
+    palette: Option<Spanned<String>>,
+    background: Option<Spanned<Colors>>,
+    seasons: Option<bool>,
+    fps: Option<Spanned<u64>>,
+    density: Option<Spanned<f32>>,
+    runes: Option<Spanned<String>>,
+    direction: Option<Spanned<String>>,
+    transition: Option<Spanned<u64>>,
+    color_mode: Option<Spanned<String>>,
+    ambiguous_width: Option<Spanned<u8>>,
+    ascii: Option<bool>,
+    rune_card: Option<bool>,
+    message: Option<String>,
+    #[serde(default)]
+    keys: BTreeMap<Spanned<String>, Spanned<Keys>>,
+    #[serde(default)]
+    rune_sets: BTreeMap<String, Spanned<String>>,
+    #[serde(default)]
+    palettes: BTreeMap<String, Spanned<PaletteDef>>,
+}
+
+/// One key or a list of keys for an action.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Keys {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// A solid background color, or the top and bottom of a vertical gradient.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Colors {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PaletteDef {
+    head: Spanned<String>,
+    /// Colors spread evenly from head to tail.
+    trail: Option<Vec<Spanned<String>>>,
+    /// Colors at positions from 0.0 (tail) to 1.0 (head).
+    stops: Option<Vec<Stop>>,
+    background: Option<Spanned<Colors>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Stop {
+    at: Spanned<f32>,
+    color: Spanned<String>,
+}
+
+/// `$XDG_CONFIG_HOME/runefall`, or `~/.config/runefall` when that is unset.
+pub fn config_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(base.join("runefall"))
+}
+
+/// 1-based line number of byte `offset` in `text`.
+fn line_of(text: &str, offset: usize) -> usize {
+    text[..offset.min(text.len())].matches('\n').count() + 1
+}
+
+impl Config {
+    /// Apply the TOML config file at `path` on top of the current settings.
+    pub fn load_file(&mut self, path: &Path) -> io::Result<()> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        self.apply_toml(&text, &path.display().to_string())
+    }
+
+    /// Apply config file contents. Errors read `origin:line: message`.
+    pub fn apply_toml(&mut self, text: &str, origin: &str) -> io::Result<()> {
+        let invalid = |span: Range<usize>, message: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{origin}:{}: {message}", line_of(text, span.start)),
+            )
+        };
+        let file: File = toml::from_str(text)
+            .map_err(|e| invalid(e.span().unwrap_or(0..0), e.message().trim_end()))?;
+        let background = |colors: Spanned<Colors>| {
+            let span = colors.span();
+            let colors = match colors.into_inner() {
+                Colors::One(color) => vec![color],
+                Colors::Many(colors) => colors,
+            };
+            colors
+                .iter()
+                .map(|hex| parse_hex(hex))
+                .collect::<Option<Vec<_>>>()
+                .and_then(|colors| Background::from_colors(&colors))
+                .ok_or_else(|| invalid(span, "background must be one or two #rrggbb colors"))
+        };
+
+        // Custom sets and palettes first, so the settings below can name them
+        for (name, glyphs) in file.rune_sets {
+            let set = RuneSet::custom(&name, glyphs.get_ref()).ok_or_else(|| {
+                invalid(glyphs.span(), &format!("rune set `{name}` has no glyphs"))
+            })?;
+            self.custom_rune_sets.push(set);
+        }
+        for (name, def) in file.palettes {
+            let span = def.span();
+            let def = def.into_inner();
+            let color = |hex: &Spanned<String>| {
+                parse_hex(hex.get_ref()).ok_or_else(|| {
+                    invalid(
+                        hex.span(),
+                        &format!("`{}` is not a #rrggbb color", hex.get_ref()),
+                    )
+                })
+            };
+            let head = color(&def.head)?;
+            let palette = match (def.trail, def.stops) {
+                (Some(trail), None) => {
+                    let trail = trail.iter().map(color).collect::<io::Result<Vec<_>>>()?;
+                    Palette::custom(&name, head, &trail)
+                }
+                (None, Some(stops)) => {
+                    let stops = stops
+                        .iter()
+                        .map(|stop| {
+                            if !(0.0..=1.0).contains(stop.at.get_ref()) {
+                                return Err(invalid(
+                                    stop.at.span(),
+                                    "stop position must be between 0.0 and 1.0",
+                                ));
+                            }
+                            Ok((*stop.at.get_ref(), color(&stop.color)?))
+                        })
+                        .collect::<io::Result<Vec<_>>>()?;
+                    Palette::gradient(&name, head, &stops)
+                }
+                _ => None,
+            };
+            let mut palette = palette.ok_or_else(|| {
+                invalid(
+                    span,
+                    &format!("palette `{name}` needs either `trail` or `stops` colors"),
+                )
+            })?;
+            if let Some(colors) = def.background {
+                palette = palette.with_background(background(colors)?);
+            }
+            self.custom_palettes.push(palette);
+        }
+
+        if let Some(name) = file.palette {
+            if !self.select_palette(name.get_ref()) {
+                return Err(invalid(
+                    name.span(),
+                    &format!("unknown palette `{}`", name.get_ref()),
+                ));
+            }
+        }
+        if let Some(seasons) = file.seasons {
+            self.seasons = seasons;
+        }
+        if let Some(colors) = file.background {
+            self.background = Some(background(colors)?);
+        }
+        if let Some(fps) = file.fps {
+            if !(5..=100).contains(fps.get_ref()) {
+                return Err(invalid(fps.span(), "fps must be between 5 and 100"));
+            }
+            self.fps = fps.into_inner();
+        }
+        if let Some(density) = file.density {
+            if !(0.05..=1.0).contains(density.get_ref()) {
+                return Err(invalid(
+                    density.span(),
+                    "density must be between 0.05 and 1.0",
+                ));
+            }
+            self.density = density.into_inner();
+        }
+        if let Some(spec) = file.runes {
+            self.rune_set = self
+                .find_rune_set(spec.get_ref())
+                .map_err(|e| invalid(spec.span(), &e.to_string()))?;
+        }
+        if let Some(name) = file.direction {
+            self.direction = Direction::from_name(name.get_ref()).ok_or_else(|| {
+                invalid(
+                    name.span(),
+                    &format!("unknown direction `{}`", name.get_ref()),
+                )
+            })?;
+        }
+        if let Some(frames) = file.transition {
+            if *frames.get_ref() > 1000 {
+                return Err(invalid(
+                    frames.span(),
+                    "transition must be at most 1000 frames",
+                ));
+            }
+            self.transition = frames.into_inner();
+        }
+        if let Some(name) = file.color_mode {
+            self.color_mode = ColorMode::from_name(name.get_ref()).ok_or_else(|| {
+                invalid(
+                    name.span(),
+                    &format!("unknown color mode `{}`", name.get_ref()),
+                )
+            })?;
+        }
+        if let Some(width) = file.ambiguous_width {
+            if !(1..=2).contains(width.get_ref()) {
+                return Err(invalid(width.span(), "ambiguous-width must be 1 or 2"));
+            }
+            self.ambiguous_width = width.into_inner();
+        }
+        if let Some(ascii) = file.ascii {
+            self.ascii = ascii;
+        }
+        if let Some(rune_card) = file.rune_card {
+            self.rune_card = rune_card;
+        }
+        if let Some(message) = file.message {
+            self.message = Some(message);
+        }
+
+        for (name, keys) in file.keys {
+            let action = self.action(name.get_ref()).ok_or_else(|| {
+                invalid(name.span(), &format!("unknown action `{}`", name.get_ref()))
+            })?;
+            let span = keys.span();
+            let names = match keys.into_inner() {
+                Keys::One(key) => vec![key],
+                Keys::Many(keys) => keys,
+            };
+            let codes = names
+                .iter()
+                .map(|key| {
+                    parse_key(key)
+                        .ok_or_else(|| invalid(span.clone(), &format!("unknown key `{key}`")))
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+            self.keys.bind(action, &codes);
+        }
+        Ok(())
+    }
+
+    /// Like [`Action::from_name`], but also knowing the custom palettes and
+    /// rune sets, e.g. `palette-dusk`.
+    fn action(&self, name: &str) -> Option<Action> {
+        let name = name.to_lowercase();
+        if let Some(palette) = name.strip_prefix("palette-") {
+            return self.find_palette(palette).map(Action::Palette);
+        }
+        if let Some(set) = name.strip_prefix("runes-") {
+            if let Some(set) = self
+                .custom_rune_sets
+                .iter()
+                .find(|custom| custom.name().eq_ignore_ascii_case(set))
+            {
+                return Some(Action::RuneSet(set.clone()));
+            }
+        }
+        Action::from_name(&name)
+    }
+
+    // ── Environment ───────────────────────────────────────────────────
+
+    /// Apply `RUNEFALL_PALETTE`, `RUNEFALL_FPS`, `RUNEFALL_DENSITY`,
+    /// `RUNEFALL_RUNES` and `RUNEFALL_DIRECTION`, plus `NO_COLOR`.
+    pub fn apply_env(&mut self) -> io::Result<()> {
+        self.apply_vars(|name| std::env::var(name).ok().filter(|value| !value.is_empty()))
+    }
+
+    fn apply_vars(&mut self, var: impl Fn(&str) -> Option<String>) -> io::Result<()> {
+        let invalid = |name: &str, message: String| {
+            io::Error::new(io::ErrorKind::InvalidInput, format!("{name}: {message}"))
+        };
+
+        if var("NO_COLOR").is_some() {
+            self.color_mode = ColorMode::Mono;
+        }
+        if let Some(name) = var("RUNEFALL_PALETTE") {
+            if !self.select_palette(&name) {
+                return Err(invalid(
+                    "RUNEFALL_PALETTE",
+                    format!("unknown palette `{name}`"),
+                ));
+            }
+        }
+        if let Some(fps) = var("RUNEFALL_FPS") {
+            self.fps = fps
+                .parse()
+                .ok()
+                .filter(|fps| (5..=100).contains(fps))
+                .ok_or_else(|| {
+                    invalid("RUNEFALL_FPS", format!("`{fps}` is not between 5 and 100"))
+                })?;
+        }
+        if let Some(density) = var("RUNEFALL_DENSITY") {
+            self.density = density
+                .parse()
+                .ok()
+                .filter(|density| (0.05..=1.0).contains(density))
+                .ok_or_else(|| {
+                    invalid(
+                        "RUNEFALL_DENSITY",
+                        format!("`{density}` is not between 0.05 and 1.0"),
+                    )
+                })?;
+        }
+        if let Some(spec) = var("RUNEFALL_RUNES") {
+            self.rune_set = self
+                .find_rune_set(&spec)
+                .map_err(|e| invalid("RUNEFALL_RUNES", e.to_string()))?;
+        }
+        if let Some(name) = var("RUNEFALL_DIRECTION") {
+            self.direction = Direction::from_name(&name).ok_or_else(|| {
+                invalid("RUNEFALL_DIRECTION", format!("unknown direction `{name}`"))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+// ── Profiles ──────────────────────────────────────────────────────────
+
+/// Where profile `name` lives: `profiles/<name>.toml` in [`config_dir`].
+pub fn profile_path(name: &str) -> io::Result<PathBuf> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidInput, msg);
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        return Err(invalid(format!("`{name}` is not a valid profile name")));
+    }
+    let dir = config_dir().ok_or_else(|| invalid("no config directory; set HOME".to_string()))?;
+    Ok(dir.join("profiles").join(format!("{name}.toml")))
+}
+
+/// The renderer's palette, rune set, density, direction and speed in config
+/// file syntax. Custom palettes and rune sets are written out in full, so the
+/// profile loads without the file that defined them.
+pub fn profile_toml(renderer: &Renderer) -> String {
+    let mut table = Table::new();
+    let palette = renderer.palette();
+    match renderer.daylight() {
+        Some(daylight) => {
+            table.insert("palette".into(), "auto".into());
+            table.insert("seasons".into(), daylight.seasons.into());
+        }
+        None => {
+            table.insert("palette".into(), palette.name().into());
+        }
+    }
+    table.insert("runes".into(), renderer.rune_set().name().into());
+    let density = (renderer.density() as f64 * 100.0).round() / 100.0;
+    table.insert("density".into(), density.into());
+    table.insert("direction".into(), renderer.direction().name().into());
+    table.insert("fps".into(), (renderer.fps() as i64).into());
+
+    if let RuneSet::Custom(custom) = renderer.rune_set() {
+        let glyphs: Vec<String> = custom
+            .glyphs()
+            .iter()
+            .zip(custom.weights())
+            .map(|(glyph, weight)| match weight {
+                1.0 => glyph.to_string(),
+                _ => format!("{glyph}:{weight}"),
+            })
+            .collect();
+        // A leading `#` would read back as a comment
+        let mut line = glyphs.join(" ");
+        if line.starts_with('#') {
+            line.insert(0, '\\');
+        }
+        let mut sets = Table::new();
+        sets.insert(custom.name().into(), line.into());
+        table.insert("rune-sets".into(), sets.into());
+    }
+    if let (Palette::Custom(custom), None) = (palette, renderer.daylight()) {
+        let hex = |(r, g, b): (u8, u8, u8)| Value::from(format!("#{r:02x}{g:02x}{b:02x}"));
+        let mut def = Table::new();
+        def.insert("head".into(), hex(custom.head()));
+        let stops: Vec<Value> = custom
+            .stops()
+            .iter()
+            .map(|&(at, color)| {
+                let mut stop = Table::new();
+                stop.insert("at".into(), ((at as f64 * 1e4).round() / 1e4).into());
+                stop.insert("color".into(), hex(color));
+                stop.into()
+            })
+            .collect();
+        def.insert("stops".into(), stops.into());
+        if let Some(background) = custom.background() {
+            let colors: Vec<Value> = background.colors().into_iter().map(hex).collect();
+            def.insert("background".into(), colors.into());
+        }
+        let mut palettes = Table::new();
+        palettes.insert(custom.name().into(), def.into());
+        table.insert("palettes".into(), palettes.into());
+    }
+    toml::to_string(&table).expect("a table of strings and numbers serializes")
+}
+
+/// Write the renderer's settings to profile `name`, returning its path.
+pub fn save_profile(renderer: &Renderer, name: &str) -> io::Result<PathBuf> {
+    let path = profile_path(name)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, profile_toml(renderer))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyCode;
+
+    const EXAMPLE: &str = r##"
+palette = "dusk"
+fps = 30
+density = 0.6
+runes = "stars"
+direction = "left"
+
+[keys]
+quit = ["x", "esc"]
+palette-dusk = "d"
+
+[rune-sets]
+stars = "★ ☆ ✶"
+
+[palettes.dusk]
+head = "#ffe0c0"
+trail = ["#ff8040", "#200818"]
+
+[palettes.aurora]
+head = "#e0ffe8"
+stops = [
+    { at = 0.0, color = "#000814" },
+    { at = 0.3, color = "#1b4965" },
+    { at = 1.0, color = "#5fffa0" },
+]
+background = ["#0a1030", "#000000"]
+"##;
+
+    fn error(text: &str) -> String {
+        Config::default()
+            .apply_toml(text, "config.toml")
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn file_sets_everything_it_names() {
+        let mut config = Config::default();
+        config.apply_toml(EXAMPLE, "config.toml").unwrap();
+        assert_eq!(config.palette.name(), "dusk");
+        let aurora = config.find_palette("Aurora").unwrap();
+        assert_eq!(
+            aurora.color(0.3, 0, 0, 0),
+            crossterm::style::Color::Rgb {
+                r: 0x1b,
+                g: 0x49,
+                b: 0x65
+            }
+        );
+        assert_eq!(
+            aurora.background(),
+            Some(Background::Vertical((0x0a, 0x10, 0x30), (0, 0, 0)))
+        );
+        assert_eq!(config.palette.background(), None);
+        assert_eq!(config.fps, 30);
+        assert_eq!(config.density, 0.6);
+        assert_eq!(config.rune_set.name(), "stars");
+        assert_eq!(config.direction, Direction::Left);
+        assert_eq!(config.keys.action(KeyCode::Char('x')), Some(&Action::Quit));
+        assert_eq!(config.keys.action(KeyCode::Char('q')), None);
+        assert_eq!(
+            config.keys.action(KeyCode::Char('d')),
+            Some(&Action::Palette(config.palette.clone()))
+        );
+        // Untouched settings keep their defaults
+        assert_eq!(config.ambiguous_width, 1);
+    }
+
+    #[test]
+    fn mistakes_are_reported_with_line_numbers() {
+        assert_eq!(
+            error("fps = 30\npallete = \"frost\"\n"),
+            "config.toml:2: unknown field `pallete`, expected one of `palette`, `background`, `seasons`, `fps`, \
+             `density`, `runes`, `direction`, `transition`, `color-mode`, `ambiguous-width`, `ascii`, \
+             `rune-card`, `message`, `keys`, `rune-sets`, `palettes`"
+        );
+        assert_eq!(
+            error("\n\nfps = 500\n"),
+            "config.toml:3: fps must be between 5 and 100"
+        );
+        assert_eq!(
+            error("palette = \"plaid\"\n"),
+            "config.toml:1: unknown palette `plaid`"
+        );
+        assert_eq!(
+            error("[keys]\nquit = \"q\"\njump = \"j\"\n"),
+            "config.toml:3: unknown action `jump`"
+        );
+        assert_eq!(
+            error("[palettes.dusk]\nhead = \"#fff\"\ntrail = [\"#000\", \"dark\"]\n"),
+            "config.toml:3: `dark` is not a #rrggbb color"
+        );
+        assert_eq!(
+            error(
+                "[palettes.dusk]\nhead = \"#fff\"\nstops = [\n  { at = 2, color = \"#000\" },\n]\n"
+            ),
+            "config.toml:4: stop position must be between 0.0 and 1.0"
+        );
+        assert_eq!(
+            error("fps = 30\nbackground = [\"#000\", \"#111\", \"#222\"]\n"),
+            "config.toml:2: background must be one or two #rrggbb colors"
+        );
+        assert!(error("fps = \n").starts_with("config.toml:1: "));
+    }
+
+    #[test]
+    fn environment_overrides_the_file() {
+        let mut config = Config::default();
+        config.apply_toml(EXAMPLE, "config.toml").unwrap();
+        let vars = [("RUNEFALL_FPS", "45"), ("RUNEFALL_PALETTE", "frost")];
+        let var = |name: &str| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        };
+        config.apply_vars(var).unwrap();
+        assert_eq!(config.fps, 45);
+        assert_eq!(config.palette, Palette::Frost);
+        assert_eq!(config.direction, Direction::Left);
+
+        let err = config
+            .apply_vars(|name| (name == "RUNEFALL_DENSITY").then(|| "lots".to_string()))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "RUNEFALL_DENSITY: `lots` is not between 0.05 and 1.0"
+        );
+    }
+
+    #[test]
+    fn profiles_restore_the_renderer_settings() {
+        let mut config = Config::default();
+        config.apply_toml(EXAMPLE, "config.toml").unwrap();
+        config.rune_set = RuneSet::custom("stars", "★:3 ☆ ✶").unwrap();
+        config.palette = config.find_palette("aurora").unwrap();
+        let mut renderer = Renderer::new(40, 10, &config);
+        renderer.change_density(0.05);
+        renderer.set_fps(83);
+        let profile = profile_toml(&renderer);
+
+        // Loads on its own, without the config file that defined the customs
+        let mut restored = Config::default();
+        restored.apply_toml(&profile, "work.toml").unwrap();
+        assert_eq!(restored.palette, config.palette);
+        assert_eq!(restored.rune_set, config.rune_set);
+        assert_eq!(restored.density, 0.65);
+        assert_eq!(restored.direction, Direction::Left);
+        assert_eq!(restored.fps, 83);
+
+        for set in RuneSet::BUILTIN {
+            let renderer = Renderer::new(
+                40,
+                10,
+                &Config {
+                    rune_set: set.clone(),
+                    ..Config::default()
+                },
+            );
+            let mut restored = Config::default();
+            restored
+                .apply_toml(&profile_toml(&renderer), "work.toml")
+                .unwrap();
+            assert_eq!(restored.rune_set, set);
+        }
+
+        // A set starting with `#` isn't saved as a comment
+        for rune_set in [
+            RuneSet::custom("hashes", "\\# ★").unwrap(),
+            RuneSet::weighted("hashes", [('#', 2.0), ('★', 1.0)]).unwrap(),
+        ] {
+            let config = Config {
+                rune_set,
+                ..Config::default()
+            };
+            let renderer = Renderer::new(40, 10, &config);
+            let mut restored = Config::default();
+            restored
+                .apply_toml(&profile_toml(&renderer), "work.toml")
+                .unwrap();
+            assert_eq!(restored.rune_set, config.rune_set);
+        }
+
+        // The time-of-day palette is saved as such, not as today's colors
+        let renderer = Renderer::new(
+            40,
+            10,
+            &Config {
+                auto_palette: true,
+                seasons: true,
+                ..Config::default()
+            },
+        );
+        let mut restored = Config::default();
+        restored
+            .apply_toml(&profile_toml(&renderer), "work.toml")
+            .unwrap();
+        assert!(restored.auto_palette && restored.seasons);
+        assert!(restored.custom_palettes.is_empty());
+        assert!(profile_path("../escape").is_err());
+    }
+}
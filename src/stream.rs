@@ -0,0 +1,83 @@
+use crate::runes::{random_rune, RuneSet};
+use rand::Rng;
+
+// ── Stream (rain drop) ───────────────────────────────────────────────
+
+pub(crate) struct Stream {
+    pub(crate) lane: u16,
+    pub(crate) pos: i32, // head position along the direction (increases over time)
+    speed: u8,
+    tick_counter: u8,
+    pub(crate) trail_len: u16,
+    pub(crate) color_seed: u8,
+    pub(crate) active: bool,
+    pub(crate) chars: Vec<char>,
+}
+
+impl Stream {
+    pub(crate) fn new(lane: u16, max_pos: u16, rng: &mut impl Rng, rune_set: &RuneSet) -> Self {
+        let trail_len = rng.gen_range(4..=max_pos.saturating_sub(2).max(6));
+        let speed = rng.gen_range(1..=4_u8);
+        let mut chars = Vec::with_capacity(trail_len as usize);
+        for _ in 0..trail_len {
+            chars.push(random_rune(rng, rune_set));
+        }
+        Stream {
+            lane,
+            pos: -(rng.gen_range(0..(max_pos as i32).max(1))),
+            speed,
+            tick_counter: 0,
+            trail_len,
+            color_seed: rng.gen(),
+            active: true,
+            chars,
+        }
+    }
+
+    pub(crate) fn reset(
+        &mut self,
+        lane: u16,
+        max_pos: u16,
+        rng: &mut impl Rng,
+        rune_set: &RuneSet,
+    ) {
+        self.lane = lane;
+        self.pos = -(rng.gen_range(0..(max_pos as i32).max(1)));
+        self.speed = rng.gen_range(1..=4);
+        self.tick_counter = 0;
+        self.trail_len = rng.gen_range(4..=max_pos.saturating_sub(2).max(6));
+        self.color_seed = rng.gen();
+        self.chars.clear();
+        for _ in 0..self.trail_len {
+            self.chars.push(random_rune(rng, rune_set));
+        }
+        self.active = true;
+    }
+
+    /// Swap each glyph that isn't in `rune_set` for one that is, with a
+    /// chance of 1 in `odds`, so a switch of sets spreads over a few frames.
+    pub(crate) fn reglyph(&mut self, odds: u32, rng: &mut impl Rng, rune_set: &RuneSet) {
+        for ch in &mut self.chars {
+            if !rune_set.contains(*ch) && rng.gen_ratio(1, odds.max(1)) {
+                *ch = random_rune(rng, rune_set);
+            }
+        }
+    }
+
+    pub(crate) fn tick(&mut self, max_pos: u16, rng: &mut impl Rng, rune_set: &RuneSet) {
+        self.tick_counter += 1;
+        if self.tick_counter >= self.speed {
+            self.tick_counter = 0;
+            self.pos += 1;
+
+            if !self.chars.is_empty() && rng.gen_ratio(1, 5) {
+                let idx = rng.gen_range(0..self.chars.len());
+                self.chars[idx] = random_rune(rng, rune_set);
+            }
+
+            if self.pos - self.trail_len as i32 > max_pos as i32 {
+                self.active = false;
+            }
+        }
+    }
+}
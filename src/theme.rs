@@ -0,0 +1,404 @@
+use crate::color::parse_hex;
+use crate::oklab::{mix, Oklab};
+use crate::palette::{Background, Palette};
+use crossterm::style::Color;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// ── Terminal theme import ─────────────────────────────────────────────
+//
+// Terminal themes all boil down to a background, a foreground and the
+// sixteen ANSI colors; only the file formats differ. Each format is read into
+// a `Theme`, which then becomes a palette in the theme's accent color.
+
+/// The colors of a terminal theme that a palette is made from.
+#[derive(Debug, Default)]
+struct Theme {
+    background: Option<(u8, u8, u8)>,
+    foreground: Option<(u8, u8, u8)>,
+    ansi: [Option<(u8, u8, u8)>; 16],
+}
+
+impl Theme {
+    /// Set a color from a name used by one of the formats: `background`,
+    /// `foreground`, `colorN`, or an ANSI color name in the normal or bright
+    /// range.
+    fn set(&mut self, name: &str, bright: bool, color: (u8, u8, u8)) {
+        const NAMES: [&str; 8] = [
+            "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+        ];
+        let name = name.to_lowercase();
+        match name.as_str() {
+            "background" => self.background = Some(color),
+            "foreground" => self.foreground = Some(color),
+            _ => {
+                let idx = match name.strip_prefix("color") {
+                    Some(idx) => idx.parse().ok(),
+                    None => NAMES
+                        .iter()
+                        .position(|ansi| *ansi == name)
+                        .map(|idx| idx + if bright { 8 } else { 0 }),
+                };
+                if let Some(slot) = idx.and_then(|idx: usize| self.ansi.get_mut(idx)) {
+                    *slot = Some(color);
+                }
+            }
+        }
+    }
+
+    /// A palette in the theme's accent, the most colorful of its red to cyan
+    /// ANSI colors: the trail runs from the accent's bright variant through
+    /// the accent itself to a shade just above the background, and the head
+    /// is the foreground tinted with the accent.
+    fn palette(&self, name: &str) -> Option<Palette> {
+        let chroma = |color: (u8, u8, u8)| Oklab::from_rgb(color).to_lch().c;
+        let accent = (1..=6)
+            .chain(9..=14)
+            .filter_map(|idx| Some((idx, self.ansi[idx]?)))
+            .max_by(|(_, a), (_, b)| chroma(*a).total_cmp(&chroma(*b)))
+            .map(|(idx, color)| (idx % 8, color));
+        let background = self.background.unwrap_or((0, 0, 0));
+        let (normal, bright) = match accent {
+            Some((idx, color)) => (
+                self.ansi[idx].unwrap_or(color),
+                self.ansi[idx + 8].unwrap_or(color),
+            ),
+            None => {
+                let color = self.foreground?;
+                (mix(background, color, 0.7), color)
+            }
+        };
+        let head = mix(bright, self.foreground.unwrap_or((255, 255, 255)), 0.6);
+        let rgb = |(r, g, b): (u8, u8, u8)| Color::Rgb { r, g, b };
+        let stops = [
+            (0.0, rgb(mix(background, normal, 0.25))),
+            (0.6, rgb(normal)),
+            (1.0, rgb(bright)),
+        ];
+        let palette = Palette::gradient(name, rgb(head), &stops)?;
+        Some(match self.background {
+            Some(color) => palette.with_background(Background::Solid(color)),
+            None => palette,
+        })
+    }
+}
+
+/// A color as the formats write it: `#rrggbb`, `0xrrggbb` or X11's
+/// `rgb:rr/gg/bb`, possibly in quotes.
+fn color(s: &str) -> Option<(u8, u8, u8)> {
+    let s = s.trim().trim_matches(|ch| ch == '"' || ch == '\'');
+    let hex = if let Some(hex) = s.strip_prefix("0x") {
+        format!("#{hex}")
+    } else if let Some(channels) = s.strip_prefix("rgb:") {
+        // Each channel has 1 to 4 hex digits, scaled by how many there are:
+        // `f` and `ffff` are both full intensity
+        let channels = channels
+            .split('/')
+            .map(|c| {
+                if !(1..=4).contains(&c.len()) || !c.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return None;
+                }
+                let value = u32::from_str_radix(c, 16).ok()?;
+                let max = (1u32 << (4 * c.len())) - 1;
+                Some(((value * 255 + max / 2) / max) as u8)
+            })
+            .collect::<Option<Vec<u8>>>()?;
+        let [r, g, b] = channels[..] else {
+            return None;
+        };
+        return Some((r, g, b));
+    } else {
+        s.to_string()
+    };
+    match parse_hex(&hex)? {
+        Color::Rgb { r, g, b } => Some((r, g, b)),
+        _ => None,
+    }
+}
+
+/// Alacritty's TOML: `[colors.primary]` with `background` and `foreground`,
+/// `[colors.normal]` and `[colors.bright]` with the ANSI color names.
+fn alacritty_toml(text: &str) -> Option<Theme> {
+    let table: toml::Table = toml::from_str(text).ok()?;
+    let colors = table.get("colors")?.as_table()?;
+    let mut theme = Theme::default();
+    for (section, bright) in [("primary", false), ("normal", false), ("bright", true)] {
+        let Some(section) = colors.get(section).and_then(|s| s.as_table()) else {
+            continue;
+        };
+        for (name, value) in section {
+            if let Some(rgb) = value.as_str().and_then(color) {
+                theme.set(name, bright, rgb);
+            }
+        }
+    }
+    Some(theme)
+}
+
+/// Alacritty's older YAML, which nests the same sections as the TOML under
+/// `colors:` by indentation.
+fn alacritty_yaml(text: &str) -> Theme {
+    let mut theme = Theme::default();
+    let mut path: Vec<(usize, String)> = Vec::new(); // (indent, key) of open sections
+    for line in text.lines() {
+        let content = line.split(" #").next().unwrap_or("").trim_end();
+        let key_value = content.trim_start();
+        if key_value.is_empty() || key_value.starts_with('#') {
+            continue;
+        }
+        let indent = content.len() - key_value.len();
+        let Some((key, value)) = key_value.split_once(':') else {
+            continue;
+        };
+        while path.last().is_some_and(|(open, _)| *open >= indent) {
+            path.pop();
+        }
+        let value = value.trim();
+        if value.is_empty() {
+            path.push((indent, key.trim().to_string()));
+            continue;
+        }
+        let sections: Vec<&str> = path.iter().map(|(_, key)| key.as_str()).collect();
+        let bright = match sections[..] {
+            ["colors", "primary"] | ["colors", "normal"] => false,
+            ["colors", "bright"] => true,
+            _ => continue,
+        };
+        if let Some(rgb) = color(value) {
+            theme.set(key.trim(), bright, rgb);
+        }
+    }
+    theme
+}
+
+/// kitty's `name value` lines, e.g. `background #1d1f21` and `color4 #81a2be`.
+fn kitty(text: &str) -> Theme {
+    let mut theme = Theme::default();
+    for line in text.lines() {
+        let mut words = line.split_whitespace();
+        if let (Some(name), Some(value)) = (words.next(), words.next()) {
+            if let Some(rgb) = color(value) {
+                theme.set(name, false, rgb);
+            }
+        }
+    }
+    theme
+}
+
+/// X resources, e.g. `*.background: #1d1f21` or `URxvt*color4: #81a2be`,
+/// including `#define` names used as values.
+fn xresources(text: &str) -> Theme {
+    let mut theme = Theme::default();
+    let mut defines: Vec<(&str, &str)> = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if let Some(define) = line.strip_prefix("#define") {
+            let mut words = define.split_whitespace();
+            if let (Some(name), Some(value)) = (words.next(), words.next()) {
+                defines.push((name, value));
+            }
+            continue;
+        }
+        if line.starts_with('!') {
+            continue;
+        }
+        let Some((resource, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        let value = defines
+            .iter()
+            .rev()
+            .find(|(name, _)| *name == value)
+            .map_or(value, |(_, value)| value);
+        // The last component names the color, whatever the class before it
+        let name = resource.rsplit(['*', '.']).next().unwrap_or("").trim();
+        if let Some(rgb) = color(value) {
+            theme.set(name, false, rgb);
+        }
+    }
+    theme
+}
+
+/// iTerm2's `.itermcolors`, an XML property list mapping names such as
+/// `Ansi 4 Color` and `Background Color` to dictionaries of `Red Component`,
+/// `Green Component` and `Blue Component` from 0.0 to 1.0.
+fn itermcolors(text: &str) -> Theme {
+    let mut theme = Theme::default();
+    let mut rest = text;
+    while let Some((name, after)) = element(rest, "key") {
+        rest = after;
+        // Other keys hold strings or numbers, not a dictionary
+        let Some((dict, after)) = element(rest, "dict").filter(|_| opens(rest, "dict")) else {
+            continue;
+        };
+        rest = after;
+
+        let mut channels = [0u8; 3];
+        let mut entries = dict;
+        while let Some((key, after)) = element(entries, "key") {
+            entries = after;
+            let Some((value, after)) = element(entries, "real").filter(|_| opens(entries, "real"))
+            else {
+                continue;
+            };
+            entries = after;
+            let channel = ["Red Component", "Green Component", "Blue Component"]
+                .iter()
+                .position(|component| *component == key.trim());
+            if let (Some(channel), Ok(value)) = (channel, value.trim().parse::<f32>()) {
+                channels[channel] = (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+            }
+        }
+        let rgb = (channels[0], channels[1], channels[2]);
+        let name = name.trim();
+        if let Some(idx) = name
+            .strip_prefix("Ansi ")
+            .and_then(|name| name.strip_suffix(" Color"))
+        {
+            theme.set(&format!("color{idx}"), false, rgb);
+        } else if let Some(name) = name.strip_suffix(" Color") {
+            theme.set(name, false, rgb);
+        }
+    }
+    theme
+}
+
+/// Whether `text` continues with a `<tag>` element.
+fn opens(text: &str, tag: &str) -> bool {
+    text.trim_start().starts_with(&format!("<{tag}>"))
+}
+
+/// Contents of the first `<tag>…</tag>` in `text`, and the text after it.
+fn element<'a>(text: &'a str, tag: &str) -> Option<(&'a str, &'a str)> {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    let start = text.find(&open)? + open.len();
+    let end = start + text[start..].find(&close)?;
+    Some((&text[start..end], &text[end + close.len()..]))
+}
+
+impl Palette {
+    /// A palette matching terminal theme `text`, named `name`. The format is
+    /// told from the contents: iTerm2 `.itermcolors`, Alacritty TOML or YAML,
+    /// X resources or kitty `.conf`. `None` when no colors are found.
+    pub fn from_theme(name: &str, text: &str) -> Option<Palette> {
+        let theme = if text.contains("<plist") {
+            itermcolors(text)
+        } else if let Some(theme) = alacritty_toml(text) {
+            theme
+        } else if text.lines().any(|line| line.trim_end() == "colors:") {
+            alacritty_yaml(text)
+        } else if text
+            .lines()
+            .any(|line| !line.starts_with('!') && line.contains("background:"))
+        {
+            xresources(text)
+        } else {
+            kitty(text)
+        };
+        theme.palette(name)
+    }
+
+    /// A palette matching the terminal theme file at `path`, named after the
+    /// file.
+    pub fn from_theme_file(path: &Path) -> io::Result<Palette> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "Theme".to_string());
+        Palette::from_theme(&name, &text).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("no theme colors in {}", path.display()),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tomorrow Night's background, foreground, blue and bright blue.
+    const BACKGROUND: (u8, u8, u8) = (0x1d, 0x1f, 0x21);
+    const BLUE: (u8, u8, u8) = (0x81, 0xa2, 0xbe);
+    const BRIGHT_BLUE: (u8, u8, u8) = (0x5f, 0xaf, 0xff);
+
+    fn check(text: &str) {
+        let palette = Palette::from_theme("tomorrow", text).expect(text);
+        assert_eq!(palette.name(), "tomorrow");
+        assert_eq!(palette.background(), Some(Background::Solid(BACKGROUND)));
+        let Palette::Custom(custom) = palette else {
+            panic!("{palette:?}");
+        };
+        assert_eq!(custom.stops()[1].1, BLUE, "{text}");
+        assert_eq!(custom.stops()[2].1, BRIGHT_BLUE, "{text}");
+    }
+
+    #[test]
+    fn reads_every_format() {
+        check(
+            "[colors.primary]\nbackground = \"#1d1f21\"\nforeground = \"#c5c8c6\"\n\
+             [colors.normal]\nblue = \"#81a2be\"\nyellow = \"#9a9580\"\n\
+             [colors.bright]\nblue = \"#5fafff\"\n",
+        );
+        check(
+            "colors:\n  primary:\n    background: '0x1d1f21'\n    foreground: '0xc5c8c6'\n\
+             \x20 normal:\n    blue:   '0x81a2be' # blue\n    yellow: '0x9a9580'\n\
+             \x20 bright:\n    blue:   '0x5fafff'\nfont:\n  size: 11\n",
+        );
+        check(
+            "# Tomorrow Night\nforeground #c5c8c6\nbackground #1d1f21\n\
+             color3 #9a9580\ncolor4 #81a2be\ncolor12 #5fafff\n",
+        );
+        check(
+            "! Tomorrow Night\n#define t_blue #81a2be\n*.foreground: #c5c8c6\n\
+             *.background: rgb:1d/1f/21\n*color3: #9a9580\nURxvt*color4: t_blue\n\
+             *.color12: #5fafff\n",
+        );
+        let component = |name: &str, (r, g, b): (u8, u8, u8)| {
+            format!(
+                "<key>{name}</key>\n<dict>\n\
+                 <key>Alpha Component</key><real>1</real>\n\
+                 <key>Color Space</key><string>sRGB</string>\n\
+                 <key>Blue Component</key><real>{}</real>\n\
+                 <key>Green Component</key><real>{}</real>\n\
+                 <key>Red Component</key><real>{}</real>\n</dict>\n",
+                b as f32 / 255.0,
+                g as f32 / 255.0,
+                r as f32 / 255.0
+            )
+        };
+        check(&format!(
+            "<?xml version=\"1.0\"?>\n<plist version=\"1.0\">\n<dict>\n{}{}{}{}</dict>\n</plist>\n",
+            component("Ansi 4 Color", BLUE),
+            component("Ansi 12 Color", BRIGHT_BLUE),
+            component("Ansi 3 Color", (0x9a, 0x95, 0x80)),
+            component("Background Color", BACKGROUND),
+        ));
+    }
+
+    #[test]
+    fn themes_without_colors_are_rejected() {
+        assert_eq!(Palette::from_theme("empty", "font_size 11\n"), None);
+        assert_eq!(color("rgb:ffff/8000/0"), Some((255, 128, 0)));
+        assert_eq!(color("rgb:f/8/0"), Some((255, 136, 0)));
+        assert_eq!(color("rgb:1d/1f/21"), Some((0x1d, 0x1f, 0x21)));
+        for bad in [
+            "rgb:€/0/0",
+            "rgb:aé/0/0",
+            "rgb:fffff/0/0",
+            "rgb:/0/0",
+            "rgb:f/f",
+        ] {
+            assert_eq!(color(bad), None, "{bad}");
+        }
+        assert_eq!(
+            Palette::from_theme("bad", "*.background: rgb:€/0/0\n*.color1: rgb:aé/0/0\n"),
+            None
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use crate::runes::{ascii_fallback, RuneSet};
+
+// ── Latin → rune transliteration ──────────────────────────────────────
+
+/// Latin spellings and the runes they map to, by sound value. Digraphs come
+/// first so `th` becomes thurisaz rather than tiwaz and hagalaz.
+type Mapping = &'static [(&'static str, &'static str)];
+
+const ELDER_MAPPING: Mapping = &[
+    ("th", "ᚦ"),
+    ("ng", "ᛜ"),
+    ("ph", "ᚠ"),
+    ("ck", "ᚲ"),
+    ("qu", "ᚲᚹ"),
+    ("a", "ᚨ"),
+    ("b", "ᛒ"),
+    ("c", "ᚲ"),
+    ("d", "ᛞ"),
+    ("e", "ᛖ"),
+    ("f", "ᚠ"),
+    ("g", "ᚷ"),
+    ("h", "ᚺ"),
+    ("i", "ᛁ"),
+    ("j", "ᛃ"),
+    ("k", "ᚲ"),
+    ("l", "ᛚ"),
+    ("m", "ᛗ"),
+    ("n", "ᚾ"),
+    ("o", "ᛟ"),
+    ("p", "ᛈ"),
+    ("q", "ᚲ"),
+    ("r", "ᚱ"),
+    ("s", "ᛊ"),
+    ("t", "ᛏ"),
+    ("u", "ᚢ"),
+    ("v", "ᚹ"),
+    ("w", "ᚹ"),
+    ("x", "ᚲᛊ"),
+    ("y", "ᛃ"),
+    ("z", "ᛉ"),
+];
+
+const YOUNGER_MAPPING: Mapping = &[
+    ("th", "ᚦ"),
+    ("ph", "ᚠ"),
+    ("ck", "ᚴ"),
+    ("qu", "ᚴᚢ"),
+    ("a", "ᛅ"),
+    ("b", "ᛒ"),
+    ("c", "ᚴ"),
+    ("d", "ᛏ"),
+    ("e", "ᛁ"),
+    ("f", "ᚠ"),
+    ("g", "ᚴ"),
+    ("h", "ᚼ"),
+    ("i", "ᛁ"),
+    ("j", "ᛁ"),
+    ("k", "ᚴ"),
+    ("l", "ᛚ"),
+    ("m", "ᛘ"),
+    ("n", "ᚾ"),
+    ("o", "ᚬ"),
+    ("p", "ᛒ"),
+    ("q", "ᚴ"),
+    ("r", "ᚱ"),
+    ("s", "ᛋ"),
+    ("t", "ᛏ"),
+    ("u", "ᚢ"),
+    ("v", "ᚢ"),
+    ("w", "ᚢ"),
+    ("x", "ᚴᛋ"),
+    ("y", "ᚢ"),
+    ("z", "ᛋ"),
+];
+
+const ANGLO_MAPPING: Mapping = &[
+    ("th", "ᚦ"),
+    ("ng", "ᛝ"),
+    ("ea", "ᛠ"),
+    ("io", "ᛡ"),
+    ("ae", "ᚫ"),
+    ("oe", "ᛟ"),
+    ("st", "ᛥ"),
+    ("ph", "ᚠ"),
+    ("ck", "ᛣ"),
+    ("qu", "ᛢ"),
+    ("a", "ᚪ"),
+    ("b", "ᛒ"),
+    ("c", "ᚳ"),
+    ("d", "ᛞ"),
+    ("e", "ᛖ"),
+    ("f", "ᚠ"),
+    ("g", "ᚷ"),
+    ("h", "ᚻ"),
+    ("i", "ᛁ"),
+    ("j", "ᛄ"),
+    ("k", "ᛣ"),
+    ("l", "ᛚ"),
+    ("m", "ᛗ"),
+    ("n", "ᚾ"),
+    ("o", "ᚩ"),
+    ("p", "ᛈ"),
+    ("q", "ᛣ"),
+    ("r", "ᚱ"),
+    ("s", "ᛋ"),
+    ("t", "ᛏ"),
+    ("u", "ᚢ"),
+    ("v", "ᚠ"),
+    ("w", "ᚹ"),
+    ("x", "ᛉ"),
+    ("y", "ᚣ"),
+    ("z", "ᛋ"),
+];
+
+const MEDIEVAL_MAPPING: Mapping = &[
+    ("th", "ᚦ"),
+    ("ph", "ᚠ"),
+    ("ck", "ᚴ"),
+    ("qu", "ᛩ"),
+    ("a", "ᛆ"),
+    ("b", "ᛒ"),
+    ("c", "ᛍ"),
+    ("d", "ᛑ"),
+    ("e", "ᛂ"),
+    ("f", "ᚠ"),
+    ("g", "ᚵ"),
+    ("h", "ᚼ"),
+    ("i", "ᛁ"),
+    ("j", "ᛁ"),
+    ("k", "ᚴ"),
+    ("l", "ᛚ"),
+    ("m", "ᛘ"),
+    ("n", "ᚿ"),
+    ("o", "ᚮ"),
+    ("p", "ᛔ"),
+    ("q", "ᛩ"),
+    ("r", "ᚱ"),
+    ("s", "ᛌ"),
+    ("t", "ᛐ"),
+    ("u", "ᚢ"),
+    ("v", "ᚡ"),
+    ("w", "ᚡ"),
+    ("x", "ᛪ"),
+    ("y", "ᚤ"),
+    ("z", "ᛎ"),
+];
+
+/// Ogham, with the forfeda for its diphthongs.
+const OGHAM_MAPPING: Mapping = &[
+    ("ng", "ᚍ"),
+    ("st", "ᚎ"),
+    ("ea", "ᚕ"),
+    ("oi", "ᚖ"),
+    ("ui", "ᚗ"),
+    ("ia", "ᚘ"),
+    ("ae", "ᚙ"),
+    ("a", "ᚐ"),
+    ("b", "ᚁ"),
+    ("c", "ᚉ"),
+    ("d", "ᚇ"),
+    ("e", "ᚓ"),
+    ("f", "ᚃ"),
+    ("g", "ᚌ"),
+    ("h", "ᚆ"),
+    ("i", "ᚔ"),
+    ("j", "ᚔ"),
+    ("k", "ᚉ"),
+    ("l", "ᚂ"),
+    ("m", "ᚋ"),
+    ("n", "ᚅ"),
+    ("o", "ᚑ"),
+    ("p", "ᚚ"),
+    ("q", "ᚊ"),
+    ("r", "ᚏ"),
+    ("s", "ᚄ"),
+    ("t", "ᚈ"),
+    ("u", "ᚒ"),
+    ("v", "ᚃ"),
+    ("w", "ᚃ"),
+    ("x", "ᚉᚄ"),
+    ("y", "ᚔ"),
+    ("z", "ᚎ"),
+];
+
+/// The mapping table of `set`, if it has one. The futharks and Ogham do;
+/// other scripts are spelled through their ASCII stand-ins instead.
+fn mapping(set: &RuneSet) -> Option<Mapping> {
+    match set {
+        RuneSet::Elder => Some(ELDER_MAPPING),
+        RuneSet::Younger => Some(YOUNGER_MAPPING),
+        RuneSet::Anglo => Some(ANGLO_MAPPING),
+        RuneSet::Medieval => Some(MEDIEVAL_MAPPING),
+        RuneSet::Ogham => Some(OGHAM_MAPPING),
+        RuneSet::OldTurkic
+        | RuneSet::OldItalic
+        | RuneSet::Gothic
+        | RuneSet::OldHungarian
+        | RuneSet::Tifinagh => None,
+        RuneSet::All | RuneSet::Mystic | RuneSet::Custom(_) => Some(ELDER_MAPPING),
+    }
+}
+
+/// Spellings to try, in order, when a script has no glyph for a letter.
+fn sound_alikes(letter: char) -> &'static [&'static str] {
+    match letter {
+        'c' => &["k", "q"],
+        'k' => &["c", "q"],
+        'q' => &["k", "c"],
+        'v' => &["w", "u", "f"],
+        'w' => &["v", "u"],
+        'x' => &["ks"],
+        'y' => &["i", "j"],
+        'j' => &["i", "y"],
+        'z' => &["s"],
+        'f' => &["v"],
+        'p' => &["b"],
+        _ => &[],
+    }
+}
+
+/// Transliterate Latin `text` into `set`. The futharks and Ogham map
+/// letters and digraphs by sound; other scripts reuse their ASCII stand-ins
+/// in reverse. Mixed, mystic and custom sets are written in Elder Futhark.
+/// Whitespace becomes a single space, anything without a rune is dropped.
+pub fn transliterate(text: &str, set: &RuneSet) -> String {
+    let text = text.to_lowercase();
+    let mut out = String::new();
+    let mut rest = text.as_str();
+    while let Some(ch) = rest.chars().next() {
+        if ch.is_whitespace() {
+            if !out.ends_with(' ') {
+                out.push(' ');
+            }
+            rest = &rest[ch.len_utf8()..];
+            continue;
+        }
+        let (latin, runes) = match mapping(set) {
+            Some(mapping) => mapping
+                .iter()
+                .find(|(latin, _)| rest.starts_with(latin))
+                .map(|&(latin, runes)| (latin.len(), Some(runes.to_string())))
+                .unwrap_or((ch.len_utf8(), None)),
+            None => (ch.len_utf8(), spell_by_fallback(ch, set)),
+        };
+        out.extend(runes);
+        rest = &rest[latin..];
+    }
+    out.trim().to_string()
+}
+
+/// Spell a letter with the glyph of `set` whose ASCII stand-in it is, or
+/// failing that, with the nearest-sounding letters.
+fn spell_by_fallback(letter: char, set: &RuneSet) -> Option<String> {
+    if !letter.is_ascii_lowercase() {
+        return None;
+    }
+    let glyph = |latin: char| {
+        set.glyphs()
+            .find(|&glyph| ascii_fallback(glyph).to_ascii_lowercase() == latin)
+    };
+    std::iter::once(letter.to_string().as_str())
+        .chain(sound_alikes(letter).iter().copied())
+        .find_map(|spelling| spelling.chars().map(glyph).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn futharks_spell_by_sound() {
+        assert_eq!(transliterate("Hail Odin", &RuneSet::Elder), "ᚺᚨᛁᛚ ᛟᛞᛁᚾ");
+        assert_eq!(transliterate("Hail Odin", &RuneSet::Younger), "ᚼᛅᛁᛚ ᚬᛏᛁᚾ");
+        assert_eq!(transliterate("hi!", &RuneSet::All), "ᚺᛁ");
+    }
+
+    #[test]
+    fn digraphs_take_precedence_over_letters() {
+        assert_eq!(transliterate("Thing", &RuneSet::Elder), "ᚦᛁᛜ");
+        assert_eq!(transliterate("earth stone", &RuneSet::Anglo), "ᛠᚱᚦ ᛥᚩᚾᛖ");
+        assert_eq!(transliterate("Sean  Ogham", &RuneSet::Ogham), "ᚄᚕᚅ ᚑᚌᚆᚐᚋ");
+    }
+
+    #[test]
+    fn mappings_cover_the_alphabet() {
+        for set in [
+            RuneSet::Elder,
+            RuneSet::Younger,
+            RuneSet::Anglo,
+            RuneSet::Medieval,
+            RuneSet::Ogham,
+        ] {
+            let mapping = mapping(&set).unwrap();
+            for letter in 'a'..='z' {
+                let latin = letter.to_string();
+                assert!(
+                    mapping.iter().any(|(l, _)| *l == latin),
+                    "{letter} in {}",
+                    set.name()
+                );
+            }
+            // Digraphs must come before the single letters they start with
+            let first_single = mapping.iter().position(|(l, _)| l.len() == 1).unwrap();
+            assert!(mapping[first_single..].iter().all(|(l, _)| l.len() == 1));
+        }
+    }
+
+    #[test]
+    fn every_set_spells_with_printable_glyphs() {
+        for set in RuneSet::BUILTIN {
+            let runes = transliterate("the quick brown fox jumps over the lazy dog", &set);
+            assert_eq!(runes.split(' ').count(), 9, "{}", set.name());
+            for ch in runes.chars().filter(|&ch| ch != ' ') {
+                assert_ne!(ascii_fallback(ch), '?', "{ch} in {}", set.name());
+            }
+        }
+    }
+}
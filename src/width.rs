@@ -0,0 +1,55 @@
+use unicode_width::UnicodeWidthChar;
+
+// ── Glyph display width ───────────────────────────────────────────────
+
+/// Symbols Unicode classes as narrow but which many fonts draw across two
+/// cells. They are treated like East Asian Ambiguous characters.
+const FONT_DEPENDENT: &[char] = &['⌘', '⏣', '⏥', '⚝'];
+
+/// Whether the width of `ch` depends on the terminal and font.
+pub fn is_ambiguous(ch: char) -> bool {
+    ch.width() != ch.width_cjk() || FONT_DEPENDENT.contains(&ch)
+}
+
+/// Number of cells `ch` occupies, with ambiguous glyphs taking
+/// `ambiguous_width` (1 or 2) cells. Never less than one.
+pub fn glyph_width(ch: char, ambiguous_width: u8) -> u16 {
+    if ambiguous_width >= 2 && is_ambiguous(ch) {
+        2
+    } else {
+        ch.width().unwrap_or(0).max(1) as u16
+    }
+}
+
+/// Display width of a string, see [`glyph_width`].
+pub fn str_width(s: &str, ambiguous_width: u8) -> u16 {
+    s.chars().map(|ch| glyph_width(ch, ambiguous_width)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runes_are_always_narrow() {
+        for ch in ['ᚠ', 'ᛟ', 'ᚁ', '᛫'] {
+            assert!(!is_ambiguous(ch), "{ch}");
+            assert_eq!(glyph_width(ch, 2), 1, "{ch}");
+        }
+    }
+
+    #[test]
+    fn ambiguous_symbols_follow_the_setting() {
+        for ch in ['◈', '⊕', '◎', '⌘', '⚝'] {
+            assert!(is_ambiguous(ch), "{ch}");
+            assert_eq!(glyph_width(ch, 1), 1, "{ch}");
+            assert_eq!(glyph_width(ch, 2), 2, "{ch}");
+        }
+    }
+
+    #[test]
+    fn wide_glyphs_are_always_wide() {
+        assert_eq!(glyph_width('🔮', 1), 2);
+        assert_eq!(str_width(" 🔮 ᚠ", 1), 5);
+    }
+}
@@ -0,0 +1,502 @@
+use crossterm::style::Color;
+use runefall::{
+    Backend, Background, Buffer, ColorMode, Config, CrosstermBackend, Daylight, Direction,
+    MemoryBackend, Palette, Renderer, RuneSet,
+};
+
+const COLS: u16 = 24;
+const ROWS: u16 = 10;
+const SEED: u64 = 0x5EED;
+
+fn renderer(config: Config) -> Renderer {
+    Renderer::new(
+        COLS,
+        ROWS,
+        &Config {
+            seed: Some(SEED),
+            ..config
+        },
+    )
+}
+
+/// A renderer with the status bar hidden, so frames show only the rain.
+fn quiet_renderer(config: Config) -> Renderer {
+    let mut renderer = renderer(config);
+    renderer.toggle_status();
+    renderer
+}
+
+fn run(renderer: &mut Renderer, backend: &mut impl Backend, ticks: usize) {
+    for _ in 0..ticks {
+        renderer.tick();
+        renderer.render(backend).unwrap();
+    }
+}
+
+/// Glyph grid, then a grid of per-cell style keys with their legend.
+fn dump(buffer: &Buffer) -> String {
+    const KEYS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+    let (cols, rows) = buffer.size();
+    let mut legend: Vec<String> = Vec::new();
+    let mut styles = String::new();
+    for y in 0..rows {
+        for x in 0..cols {
+            let cell = buffer.get(x, y).unwrap();
+            if cell.ch == ' ' {
+                styles.push('.');
+                continue;
+            }
+            let mut style = format!("{:?} {:?}", cell.fg, cell.attrs);
+            if cell.bg != Color::Reset {
+                style.push_str(&format!(" on {:?}", cell.bg));
+            }
+            let idx = match legend.iter().position(|s| *s == style) {
+                Some(idx) => idx,
+                None => {
+                    legend.push(style);
+                    legend.len() - 1
+                }
+            };
+            styles.push(KEYS.chars().nth(idx % KEYS.len()).unwrap());
+        }
+        styles.push('\n');
+    }
+
+    let mut out = buffer.lines().join("\n");
+    out.push_str("\n\n");
+    out.push_str(&styles);
+    for (style, key) in legend.iter().zip(KEYS.chars().cycle()) {
+        out.push_str(&format!("\n{key} = {style}"));
+    }
+    out
+}
+
+fn snapshot_after(renderer: &mut Renderer, ticks: usize) -> String {
+    let (cols, rows) = renderer.size();
+    let mut backend =
+        MemoryBackend::new(cols, rows).with_ambiguous_width(renderer.ambiguous_width());
+    run(renderer, &mut backend, ticks);
+    assert_eq!(backend.buffer(), renderer.buffer());
+    dump(backend.buffer())
+}
+
+#[test]
+fn palettes() {
+    for palette in Palette::BUILTIN {
+        let name = format!("palette_{}", palette.name().to_lowercase());
+        let mut renderer = quiet_renderer(Config {
+            palette,
+            ..Config::default()
+        });
+        insta::assert_snapshot!(name, snapshot_after(&mut renderer, 12));
+    }
+}
+
+#[test]
+fn rune_sets() {
+    for rune_set in RuneSet::BUILTIN {
+        let mut renderer = quiet_renderer(Config::default());
+        let name = format!("runes_{}", rune_set.name().to_lowercase().replace(' ', "_"));
+        renderer.set_rune_set(rune_set);
+        // Respawn so whole trails come from the chosen set
+        renderer.resize(COLS, ROWS);
+        insta::assert_snapshot!(name, snapshot_after(&mut renderer, 12));
+    }
+}
+
+#[test]
+fn custom_rune_set() {
+    let custom = RuneSet::custom("Stars", "★ ☆ ✶ ✷").unwrap();
+    let mut renderer = quiet_renderer(Config {
+        rune_set: custom.clone(),
+        ..Config::default()
+    });
+    assert_eq!(renderer.rune_set(), &custom);
+    insta::assert_snapshot!(snapshot_after(&mut renderer, 12));
+}
+
+#[test]
+fn cycling_visits_custom_sets_after_builtins() {
+    let custom = RuneSet::custom("Stars", "★☆").unwrap();
+    let mut renderer = renderer(Config {
+        custom_rune_sets: vec![custom.clone()],
+        ..Config::default()
+    });
+    let mut seen = Vec::new();
+    for _ in 0..RuneSet::BUILTIN.len() + 1 {
+        renderer.cycle_rune_set();
+        seen.push(renderer.rune_set().name().to_string());
+    }
+    assert_eq!(seen[RuneSet::BUILTIN.len() - 2], "Tifinagh");
+    assert_eq!(seen[RuneSet::BUILTIN.len() - 1], "Stars");
+    assert_eq!(seen[RuneSet::BUILTIN.len()], "All");
+}
+
+#[test]
+fn cycling_visits_custom_palettes_after_builtins() {
+    let white = crossterm::style::Color::Rgb {
+        r: 255,
+        g: 255,
+        b: 255,
+    };
+    let custom = Palette::gradient("Dusk", white, &[(0.5, white)]).unwrap();
+    let mut renderer = renderer(Config {
+        custom_palettes: vec![custom],
+        ..Config::default()
+    });
+    let mut seen = Vec::new();
+    for _ in 0..Palette::BUILTIN.len() + 1 {
+        renderer.cycle_palette();
+        seen.push(renderer.palette().name().to_string());
+    }
+    assert_eq!(seen[Palette::BUILTIN.len() - 2], "Blink");
+    assert_eq!(seen[Palette::BUILTIN.len() - 1], "Dusk");
+    assert_eq!(seen[Palette::BUILTIN.len()], "Arcane");
+}
+
+#[test]
+fn status_bar_names_custom_sets() {
+    let config = Config {
+        rune_set: RuneSet::custom("Stars", "★☆").unwrap(),
+        seed: Some(SEED),
+        ..Config::default()
+    };
+    let mut renderer = Renderer::new(60, ROWS, &config);
+    renderer.draw();
+    let status = renderer.buffer().lines().pop().unwrap();
+    assert!(status.contains("🔮 Stars |"), "{status}");
+}
+
+#[test]
+fn notices_show_even_with_the_status_bar_hidden() {
+    let mut renderer = quiet_renderer(Config::default());
+    renderer.notify("Saved profile work");
+    renderer.tick();
+    renderer.draw();
+    let status = renderer.buffer().lines().pop().unwrap();
+    assert!(status.ends_with(" Saved profile work "), "{status}");
+
+    // Gone once the status bar would have hidden again
+    for _ in 0..Config::default().fps * 3 {
+        renderer.tick();
+    }
+    assert_eq!(renderer.notice(), None);
+}
+
+#[test]
+fn directions() {
+    for direction in [
+        Direction::Down,
+        Direction::Up,
+        Direction::Left,
+        Direction::Right,
+    ] {
+        let mut renderer = quiet_renderer(Config::default());
+        renderer.change_direction(direction);
+        let name = format!("direction_{direction:?}").to_lowercase();
+        insta::assert_snapshot!(name, snapshot_after(&mut renderer, 12));
+    }
+}
+
+#[test]
+fn color_modes() {
+    for color_mode in [ColorMode::Ansi256, ColorMode::Ansi16, ColorMode::Mono] {
+        let mut renderer = quiet_renderer(Config {
+            color_mode,
+            ..Config::default()
+        });
+        let name = format!("color_mode_{}", color_mode.name());
+        insta::assert_snapshot!(name, snapshot_after(&mut renderer, 30));
+    }
+}
+
+#[test]
+fn wide_glyphs_get_two_cells_per_lane() {
+    for direction in [Direction::Down, Direction::Right] {
+        let mut renderer = quiet_renderer(Config {
+            ambiguous_width: 2,
+            ..Config::default()
+        });
+        renderer.set_rune_set(RuneSet::Mystic);
+        renderer.change_direction(direction);
+        let name = format!("ambiguous_wide_{direction:?}").to_lowercase();
+        insta::assert_snapshot!(name, snapshot_after(&mut renderer, 12));
+    }
+}
+
+#[test]
+fn ascii_fallback() {
+    let mut renderer = renderer(Config {
+        ascii: true,
+        ..Config::default()
+    });
+    insta::assert_snapshot!(snapshot_after(&mut renderer, 12));
+}
+
+#[test]
+fn message_forms_then_dissolves() {
+    let mut renderer = quiet_renderer(Config {
+        rune_set: RuneSet::Elder,
+        message: Some("Hail Odin".to_string()),
+        ..Config::default()
+    });
+    assert_eq!(renderer.message(), Some("Hail Odin"));
+    insta::assert_snapshot!(snapshot_after(&mut renderer, 150));
+    // Centered on the middle row; rain may still fall through the word gap
+    let row = |renderer: &Renderer| -> String {
+        (7..16)
+            .filter(|&x| x != 11)
+            .map(|x| renderer.buffer().get(x, 5).unwrap().ch)
+            .collect()
+    };
+    assert_eq!(row(&renderer), "ᚺᚨᛁᛚᛟᛞᛁᚾ");
+
+    let mut backend = MemoryBackend::new(COLS, ROWS);
+    run(&mut renderer, &mut backend, 200);
+    assert_ne!(row(&renderer), "ᚺᚨᛁᛚᛟᛞᛁᚾ");
+}
+
+#[test]
+fn resize_respawns_and_repaints() {
+    let mut renderer = quiet_renderer(Config::default());
+    let mut backend = MemoryBackend::new(COLS, ROWS);
+    run(&mut renderer, &mut backend, 6);
+
+    renderer.resize(COLS / 2, ROWS / 2);
+    backend.resize(COLS / 2, ROWS / 2);
+    run(&mut renderer, &mut backend, 6);
+
+    assert_eq!(backend.buffer().size(), (COLS / 2, ROWS / 2));
+    assert_eq!(backend.buffer(), renderer.buffer());
+    insta::assert_snapshot!(dump(backend.buffer()));
+}
+
+#[test]
+fn status_bar() {
+    let config = Config {
+        seed: Some(SEED),
+        ..Config::default()
+    };
+    let mut renderer = Renderer::new(60, ROWS, &config);
+    insta::assert_snapshot!("status_bar_shown", snapshot_after(&mut renderer, 1));
+
+    // Fades during its last second on screen, then disappears
+    let fps = renderer.fps() as usize;
+    insta::assert_snapshot!(
+        "status_bar_fading",
+        snapshot_after(&mut renderer, fps * 2 + 10)
+    );
+    insta::assert_snapshot!("status_bar_hidden", snapshot_after(&mut renderer, fps));
+}
+
+#[test]
+fn rune_card_names_a_highlighted_rune() {
+    let config = Config {
+        seed: Some(SEED),
+        rune_set: RuneSet::Elder,
+        rune_card: true,
+        ..Config::default()
+    };
+    let mut renderer = Renderer::new(60, ROWS, &config);
+    renderer.toggle_status();
+    insta::assert_snapshot!(snapshot_after(&mut renderer, 12));
+
+    let info = renderer.card().unwrap();
+    assert!(renderer.buffer().lines()[1].contains(info.name));
+    assert!(RuneSet::Elder.contains(info.glyph));
+
+    renderer.toggle_card();
+    renderer.tick();
+    renderer.draw();
+    assert_eq!(renderer.card(), None);
+}
+
+#[test]
+fn same_seed_produces_identical_output() {
+    let frames = || {
+        let mut renderer = renderer(Config::default());
+        let mut backend = CrosstermBackend::new(Vec::new());
+        run(&mut renderer, &mut backend, 30);
+        backend.writer_mut().clone()
+    };
+    assert_eq!(frames(), frames());
+}
+
+#[test]
+fn palette_switches_crossfade() {
+    let mut renderer = quiet_renderer(Config {
+        palette: Palette::Emerald,
+        ..Config::default()
+    });
+    let frost_head = Palette::Frost.head_color(0, 0, 0);
+    let emerald_head = Palette::Emerald.head_color(0, 0, 0);
+    let head = |renderer: &Renderer| {
+        renderer
+            .cells()
+            .find(|(_, cell)| cell.fg == frost_head || cell.fg == emerald_head)
+            .map(|(_, cell)| cell.fg)
+    };
+    let heads = |renderer: &Renderer| {
+        let mut colors: Vec<_> = renderer.cells().map(|(_, cell)| cell.fg).collect();
+        colors.dedup();
+        colors
+    };
+
+    for _ in 0..12 {
+        renderer.tick();
+    }
+    renderer.set_palette(Palette::Frost);
+    renderer.tick();
+    let midway = heads(&renderer);
+    assert!(!midway.contains(&frost_head) && !midway.contains(&emerald_head));
+
+    for _ in 0..Config::default().transition {
+        renderer.tick();
+    }
+    assert_eq!(head(&renderer), Some(frost_head));
+
+    // Switching again halfway through a fade carries on from the blend on
+    // screen instead of jumping
+    renderer.set_palette(Palette::Ember);
+    for _ in 0..Config::default().transition / 2 {
+        renderer.tick();
+    }
+    let before: Vec<_> = renderer.cells().collect();
+    renderer.set_palette(Palette::Arcane);
+    assert_eq!(renderer.cells().collect::<Vec<_>>(), before);
+    for _ in 0..Config::default().transition {
+        renderer.tick();
+    }
+    assert!(renderer
+        .cells()
+        .any(|(_, cell)| cell.fg == Palette::Arcane.head_color(0, 0, 0)));
+}
+
+#[test]
+fn backgrounds_fill_the_screen_behind_the_rain() {
+    let navy = (10, 16, 48);
+    let mut renderer = quiet_renderer(Config {
+        background: Some(Background::Vertical(navy, (0, 0, 0))),
+        ..Config::default()
+    });
+    insta::assert_snapshot!(snapshot_after(&mut renderer, 12));
+
+    // Erased cells show the background, top row navy and bottom row black
+    let buffer = renderer.buffer();
+    let bg = |y| {
+        (0..COLS)
+            .map(|x| buffer.get(x, y).unwrap())
+            .find(|cell| cell.ch == ' ')
+            .map(|cell| cell.bg)
+    };
+    assert_eq!(
+        bg(0),
+        Some(Color::Rgb {
+            r: 10,
+            g: 16,
+            b: 48
+        })
+    );
+    assert_eq!(bg(ROWS - 1), Some(Color::Rgb { r: 0, g: 0, b: 0 }));
+
+    // The dim end of each trail sinks into the background; heads stay put
+    let mut bare = quiet_renderer(Config::default());
+    for _ in 0..12 {
+        bare.tick();
+    }
+    let head = Palette::Arcane.head_color(0, 0, 0);
+    let pairs: Vec<_> = renderer.cells().zip(bare.cells()).collect();
+    assert!(pairs
+        .iter()
+        .all(|((pos, _), (bare_pos, _))| pos == bare_pos));
+    assert!(pairs
+        .iter()
+        .filter(|(_, (_, cell))| cell.fg == head)
+        .all(|((_, cell), _)| cell.fg == head));
+    assert!(pairs
+        .iter()
+        .any(|((_, cell), (_, bare))| cell.fg != bare.fg));
+
+    // Mono terminals keep their own background
+    renderer.set_color_mode(ColorMode::Mono);
+    renderer.draw();
+    assert!((0..ROWS).all(|y| renderer.buffer().get(0, y).unwrap().bg == Color::Reset));
+}
+
+#[test]
+fn auto_palette_follows_the_clock_until_a_palette_is_picked() {
+    let mut renderer = quiet_renderer(Config {
+        auto_palette: true,
+        ..Config::default()
+    });
+    assert_eq!(renderer.daylight(), Some(Daylight::default()));
+    assert!(renderer.palette().name().starts_with("Auto: "));
+    for _ in 0..40 {
+        renderer.tick();
+    }
+    assert!(renderer.palette().name().starts_with("Auto: "));
+
+    renderer.set_palette(Palette::Frost);
+    for _ in 0..40 {
+        renderer.tick();
+    }
+    assert_eq!(renderer.daylight(), None);
+    assert_eq!(*renderer.palette(), Palette::Frost);
+}
+
+#[test]
+fn rune_set_switches_reglyph_existing_streams() {
+    let mut renderer = quiet_renderer(Config {
+        rune_set: RuneSet::Elder,
+        ..Config::default()
+    });
+    for _ in 0..12 {
+        renderer.tick();
+    }
+    renderer.set_rune_set(RuneSet::Ogham);
+    let foreign = |renderer: &Renderer| {
+        renderer
+            .cells()
+            .filter(|(_, cell)| !RuneSet::Ogham.contains(cell.ch))
+            .count()
+    };
+    assert!(foreign(&renderer) > 0);
+
+    // Not all at once
+    renderer.tick();
+    assert!(foreign(&renderer) > 0);
+
+    for _ in 0..Config::default().transition {
+        renderer.tick();
+    }
+    assert_eq!(foreign(&renderer), 0);
+}
+
+#[test]
+fn rune_set_switches_at_once_without_a_transition() {
+    let mut renderer = quiet_renderer(Config {
+        rune_set: RuneSet::Elder,
+        transition: 0,
+        ..Config::default()
+    });
+    for _ in 0..12 {
+        renderer.tick();
+    }
+    renderer.set_rune_set(RuneSet::Ogham);
+    assert!(renderer.cells().count() > 0);
+    assert!(renderer
+        .cells()
+        .all(|(_, cell)| RuneSet::Ogham.contains(cell.ch)));
+}
+
+#[test]
+fn only_changed_cells_are_written() {
+    let mut renderer = quiet_renderer(Config::default());
+    let mut backend = CrosstermBackend::new(Vec::new());
+    run(&mut renderer, &mut backend, 1);
+    backend.writer_mut().clear();
+
+    // Nothing changes when the same frame is rendered twice
+    renderer.render(&mut backend).unwrap();
+    assert!(backend.writer_mut().is_empty());
+}